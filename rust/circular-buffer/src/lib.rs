@@ -4,6 +4,8 @@ use std::fmt;
 pub enum Error {
     EmptyBuffer,
     FullBuffer,
+    /// The other half of a split buffer was dropped (see [`spsc`]).
+    Disconnected,
 }
 
 #[derive(Clone)]
@@ -94,4 +96,256 @@ impl<T: Clone> CircularBuffer<T> {
             self.reader_pos = (self.reader_pos + 1) % self.capacity;
         }
     }
+
+    /// Change the capacity while keeping unread elements in order. When
+    /// shrinking below the number of unread elements, the oldest are
+    /// evicted, matching what [`CircularBuffer::overwrite`] would have
+    /// done.
+    pub fn resize(&mut self, new_capacity: usize) {
+        let unread = self.len;
+        let mut drained: Vec<T> = (0..unread)
+            .map(|offset| {
+                self.buf[(self.reader_pos + offset) % self.capacity]
+                    .take()
+                    .expect("unread slots hold elements")
+            })
+            .collect();
+        let kept = drained.split_off(unread.saturating_sub(new_capacity));
+        self.len = kept.len();
+        self.buf = vec![None; new_capacity];
+        for (slot, element) in self.buf.iter_mut().zip(kept) {
+            *slot = Some(element);
+        }
+        self.capacity = new_capacity;
+        self.reader_pos = 0;
+        self.writer_pos = if new_capacity == 0 {
+            0
+        } else {
+            self.len % new_capacity
+        };
+    }
+
+    /// Split the buffer into connected single-producer/single-consumer
+    /// halves that can live on different threads.
+    pub fn split(self) -> (spsc::Producer<T>, spsc::Consumer<T>) {
+        spsc::channel(self)
+    }
+
+    /// The unread elements, oldest first, without consuming them.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buf: &self.buf,
+            pos: self.reader_pos,
+            remaining: self.len,
+        }
+    }
+
+    /// Like [`CircularBuffer::iter`], but the elements can be edited in
+    /// place.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (front, back) = self.buf.split_at_mut(self.reader_pos);
+        back.iter_mut()
+            .chain(front.iter_mut())
+            .filter_map(Option::as_mut)
+            .take(self.len)
+    }
+}
+
+/// Borrowing iterator over the unread elements, oldest first.
+pub struct Iter<'a, T> {
+    buf: &'a [Option<T>],
+    pos: usize,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self.buf[self.pos]
+            .as_ref()
+            .expect("unread slots hold elements");
+        self.pos = (self.pos + 1) % self.buf.len();
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Consuming iterator: drains the buffer oldest first.
+pub struct IntoIter<T>(CircularBuffer<T>);
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.read().ok()
+    }
+}
+
+impl<T: Clone> IntoIterator for CircularBuffer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a CircularBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+pub mod spsc {
+    //! Single-producer/single-consumer handles over one shared buffer,
+    //! so one thread can write while another reads. Writes wake blocked
+    //! readers and vice versa; neither handle is cloneable, which is
+    //! what keeps this single-producer and single-consumer. Dropping
+    //! either handle disconnects the channel: the survivor is woken if
+    //! it is blocked, reads drain whatever is already buffered, and
+    //! everything else fails with [`Error::Disconnected`].
+
+    use super::{CircularBuffer, Error};
+    use std::sync::{Arc, Condvar, Mutex};
+
+    struct State<T> {
+        buffer: CircularBuffer<T>,
+        // Set by whichever handle drops first. Kept under the same
+        // mutex as the buffer so a drop between a full/empty check and
+        // the condvar wait cannot lose its wakeup.
+        closed: bool,
+    }
+
+    struct Shared<T> {
+        state: Mutex<State<T>>,
+        readable: Condvar,
+        writable: Condvar,
+    }
+
+    impl<T> Shared<T> {
+        fn close(&self) {
+            self.state.lock().expect("buffer lock poisoned").closed = true;
+            self.readable.notify_all();
+            self.writable.notify_all();
+        }
+    }
+
+    pub struct Producer<T>(Arc<Shared<T>>);
+
+    pub struct Consumer<T>(Arc<Shared<T>>);
+
+    impl<T> Drop for Producer<T> {
+        fn drop(&mut self) {
+            self.0.close();
+        }
+    }
+
+    impl<T> Drop for Consumer<T> {
+        fn drop(&mut self) {
+            self.0.close();
+        }
+    }
+
+    pub(crate) fn channel<T>(buffer: CircularBuffer<T>) -> (Producer<T>, Consumer<T>) {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                buffer,
+                closed: false,
+            }),
+            readable: Condvar::new(),
+            writable: Condvar::new(),
+        });
+        (Producer(Arc::clone(&shared)), Consumer(shared))
+    }
+
+    impl<T: Clone> Producer<T> {
+        /// Non-blocking write: fails with [`Error::FullBuffer`] rather
+        /// than waiting for the consumer, or with
+        /// [`Error::Disconnected`] once the consumer is gone.
+        pub fn write(&self, element: T) -> Result<(), Error> {
+            let mut state = self.0.state.lock().expect("buffer lock poisoned");
+            if state.closed {
+                return Err(Error::Disconnected);
+            }
+            let result = state.buffer.write(element);
+            if result.is_ok() {
+                self.0.readable.notify_one();
+            }
+            result
+        }
+
+        /// Block until the consumer has made room, then write. Fails
+        /// with [`Error::Disconnected`] if the consumer is dropped
+        /// first.
+        pub fn write_blocking(&self, element: T) -> Result<(), Error> {
+            let mut state = self.0.state.lock().expect("buffer lock poisoned");
+            while state.buffer.is_full() && !state.closed {
+                state = self.0.writable.wait(state).expect("buffer lock poisoned");
+            }
+            if state.closed {
+                return Err(Error::Disconnected);
+            }
+            state.buffer.write(element).expect("waited for space");
+            self.0.readable.notify_one();
+            Ok(())
+        }
+
+        /// Never blocks: evicts the oldest unread element when full.
+        /// Writes to a disconnected buffer are silently discarded.
+        pub fn overwrite(&self, element: T) {
+            let mut state = self.0.state.lock().expect("buffer lock poisoned");
+            if !state.closed {
+                state.buffer.overwrite(element);
+                self.0.readable.notify_one();
+            }
+        }
+    }
+
+    impl<T: Clone> Consumer<T> {
+        /// Non-blocking read: fails with [`Error::EmptyBuffer`] rather
+        /// than waiting for the producer. Elements written before the
+        /// producer was dropped are still delivered; once those are
+        /// drained the error becomes [`Error::Disconnected`].
+        pub fn read(&self) -> Result<T, Error> {
+            let mut state = self.0.state.lock().expect("buffer lock poisoned");
+            match state.buffer.read() {
+                Ok(element) => {
+                    self.0.writable.notify_one();
+                    Ok(element)
+                }
+                Err(Error::EmptyBuffer) if state.closed => Err(Error::Disconnected),
+                Err(error) => Err(error),
+            }
+        }
+
+        /// Block until the producer has written something, then read
+        /// it. Fails with [`Error::Disconnected`] if the producer is
+        /// dropped while the buffer is empty.
+        pub fn read_blocking(&self) -> Result<T, Error> {
+            let mut state = self.0.state.lock().expect("buffer lock poisoned");
+            while state.buffer.is_empty() && !state.closed {
+                state = self.0.readable.wait(state).expect("buffer lock poisoned");
+            }
+            match state.buffer.read() {
+                Ok(element) => {
+                    self.0.writable.notify_one();
+                    Ok(element)
+                }
+                Err(_) => Err(Error::Disconnected),
+            }
+        }
+    }
 }
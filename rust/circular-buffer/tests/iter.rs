@@ -0,0 +1,60 @@
+use circular_buffer::CircularBuffer;
+
+fn filled() -> CircularBuffer<u8> {
+    let mut buffer = CircularBuffer::new(3);
+    for value in 1..=3 {
+        buffer.write(value).unwrap();
+    }
+    buffer
+}
+
+#[test]
+fn iter_yields_read_order_without_draining() {
+    let mut buffer = filled();
+    buffer.read().unwrap();
+    buffer.write(4).unwrap();
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    // still all there afterwards
+    assert_eq!(buffer.read(), Ok(2));
+}
+
+#[test]
+fn iter_reports_its_length() {
+    let buffer = filled();
+    assert_eq!(buffer.iter().len(), 3);
+}
+
+#[test]
+fn iter_mut_edits_in_place() {
+    let mut buffer = filled();
+    for value in buffer.iter_mut() {
+        *value *= 10;
+    }
+    assert_eq!(buffer.read(), Ok(10));
+    assert_eq!(buffer.read(), Ok(20));
+}
+
+#[test]
+fn into_iterator_drains_oldest_first() {
+    let mut buffer = filled();
+    buffer.read().unwrap();
+    buffer.overwrite(4);
+    buffer.overwrite(5);
+    assert_eq!(buffer.into_iter().collect::<Vec<_>>(), [3, 4, 5]);
+}
+
+#[test]
+fn borrowing_for_loops_work() {
+    let buffer = filled();
+    let mut total = 0;
+    for value in &buffer {
+        total += value;
+    }
+    assert_eq!(total, 6);
+}
+
+#[test]
+fn an_empty_buffer_iterates_nothing() {
+    let buffer: CircularBuffer<i32> = CircularBuffer::new(4);
+    assert_eq!(buffer.iter().count(), 0);
+}
@@ -0,0 +1,58 @@
+use circular_buffer::{CircularBuffer, Error};
+
+#[test]
+fn growing_keeps_elements_and_frees_space() {
+    let mut buffer = CircularBuffer::new(2);
+    buffer.write(1).unwrap();
+    buffer.write(2).unwrap();
+    buffer.resize(4);
+    buffer.write(3).unwrap();
+    buffer.write(4).unwrap();
+    assert_eq!(buffer.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn shrinking_evicts_the_oldest() {
+    let mut buffer = CircularBuffer::new(4);
+    for value in 1..=4 {
+        buffer.write(value).unwrap();
+    }
+    buffer.resize(2);
+    assert_eq!(buffer.read(), Ok(3));
+    assert_eq!(buffer.read(), Ok(4));
+    assert_eq!(buffer.read(), Err(Error::EmptyBuffer));
+}
+
+#[test]
+fn resize_handles_a_wrapped_buffer() {
+    let mut buffer = CircularBuffer::new(3);
+    for value in 1..=3 {
+        buffer.write(value).unwrap();
+    }
+    buffer.read().unwrap();
+    buffer.write(4).unwrap();
+    buffer.resize(5);
+    assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    buffer.write(5).unwrap();
+    assert_eq!(buffer.read(), Ok(2));
+}
+
+#[test]
+fn a_full_resized_buffer_is_still_full() {
+    let mut buffer = CircularBuffer::new(3);
+    for value in 1..=3 {
+        buffer.write(value).unwrap();
+    }
+    buffer.resize(3);
+    assert_eq!(buffer.write(9), Err(Error::FullBuffer));
+    buffer.overwrite(9);
+    assert_eq!(buffer.read(), Ok(2));
+}
+
+#[test]
+fn resizing_to_zero_empties_the_buffer() {
+    let mut buffer = CircularBuffer::new(2);
+    buffer.write(1).unwrap();
+    buffer.resize(0);
+    assert_eq!(buffer.read(), Err(Error::EmptyBuffer));
+}
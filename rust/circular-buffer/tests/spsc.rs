@@ -0,0 +1,83 @@
+use circular_buffer::{CircularBuffer, Error};
+use std::thread;
+
+#[test]
+fn handles_are_send() {
+    fn assert_send<T: Send>(_: &T) {}
+    let (producer, consumer) = CircularBuffer::<i32>::new(1).split();
+    assert_send(&producer);
+    assert_send(&consumer);
+}
+
+#[test]
+fn non_blocking_variants_report_full_and_empty() {
+    let (producer, consumer) = CircularBuffer::new(1).split();
+    assert_eq!(consumer.read(), Err(Error::EmptyBuffer));
+    producer.write(1).unwrap();
+    assert_eq!(producer.write(2), Err(Error::FullBuffer));
+    assert_eq!(consumer.read(), Ok(1));
+}
+
+#[test]
+fn overwrite_evicts_across_the_split() {
+    let (producer, consumer) = CircularBuffer::new(2).split();
+    producer.write(1).unwrap();
+    producer.write(2).unwrap();
+    producer.overwrite(3);
+    assert_eq!(consumer.read(), Ok(2));
+    assert_eq!(consumer.read(), Ok(3));
+}
+
+#[test]
+fn a_thread_can_write_while_another_reads() {
+    let (producer, consumer) = CircularBuffer::new(4).split();
+    let writer = thread::spawn(move || {
+        for value in 0..1000 {
+            producer.write_blocking(value).unwrap();
+        }
+    });
+    let values: Vec<i32> = (0..1000)
+        .map(|_| consumer.read_blocking().unwrap())
+        .collect();
+    writer.join().unwrap();
+    assert_eq!(values, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn blocking_read_waits_for_a_slow_producer() {
+    let (producer, consumer) = CircularBuffer::new(1).split();
+    let writer = thread::spawn(move || {
+        thread::sleep(std::time::Duration::from_millis(50));
+        producer.write_blocking(42).unwrap();
+    });
+    assert_eq!(consumer.read_blocking(), Ok(42));
+    writer.join().unwrap();
+}
+
+#[test]
+fn reads_drain_the_buffer_after_the_producer_is_gone() {
+    let (producer, consumer) = CircularBuffer::new(2).split();
+    producer.write(1).unwrap();
+    drop(producer);
+    assert_eq!(consumer.read(), Ok(1));
+    assert_eq!(consumer.read(), Err(Error::Disconnected));
+}
+
+#[test]
+fn dropping_the_producer_unblocks_an_empty_reader() {
+    let (producer, consumer) = CircularBuffer::<i32>::new(1).split();
+    let reader = thread::spawn(move || consumer.read_blocking());
+    thread::sleep(std::time::Duration::from_millis(50));
+    drop(producer);
+    assert_eq!(reader.join().unwrap(), Err(Error::Disconnected));
+}
+
+#[test]
+fn dropping_the_consumer_unblocks_a_full_writer() {
+    let (producer, consumer) = CircularBuffer::new(1).split();
+    producer.write(1).unwrap();
+    let writer = thread::spawn(move || producer.write_blocking(2));
+    thread::sleep(std::time::Duration::from_millis(50));
+    drop(consumer);
+    assert_eq!(writer.join().unwrap(), Err(Error::Disconnected));
+}
@@ -1,4 +1,95 @@
 pub mod graph {
+    use std::fmt;
+
+    /// DOT's reserved words; they are valid IDs only when quoted.
+    const KEYWORDS: [&str; 6] = ["digraph", "edge", "graph", "node", "strict", "subgraph"];
+
+    /// Quote a DOT identifier if it can't stand bare. Bare identifiers
+    /// are alphanumerics-plus-underscore (unicode letters included, per
+    /// the DOT grammar) not starting with a digit, or numerals, and
+    /// never keywords; everything else is double-quoted with `"` and
+    /// `\` escaped.
+    pub(crate) fn quote(id: &str) -> String {
+        let keyword = KEYWORDS
+            .iter()
+            .any(|keyword| keyword.eq_ignore_ascii_case(id));
+        let alphanumeric = !keyword
+            && !id.is_empty()
+            && !id.starts_with(|c: char| c.is_ascii_digit())
+            && id.chars().all(|c| c.is_alphanumeric() || c == '_');
+        let numeral = !id.is_empty()
+            && id
+                .strip_prefix('-')
+                .unwrap_or(id)
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.')
+            && id.chars().filter(|&c| c == '.').count() <= 1
+            && id != "-"
+            && id != "."
+            && id != "-.";
+        if alphanumeric || numeral {
+            id.to_owned()
+        } else {
+            let escaped = id.replace('\\', "\\\\").replace('"', "\\\"");
+            format!("\"{}\"", escaped)
+        }
+    }
+
+    /// Returned by the checked attribute merges when a key is already
+    /// set; carries the conflicting key.
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct AttrConflict(pub String);
+
+    /// How an attribute value is written out: plain text, quoted as
+    /// needed, or an HTML-like label — DOT's `label=<...>` form, which
+    /// must reach the output with its angle brackets intact and its
+    /// contents unescaped.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AttrValue<'a> {
+        Text(&'a str),
+        Html(&'a str),
+    }
+
+    impl<'a> AttrValue<'a> {
+        /// Classify a stored value: values wrapped in `<...>` (as
+        /// produced by [`html`]) are HTML-like, everything else is text.
+        pub fn of(value: &'a str) -> Self {
+            value
+                .strip_prefix('<')
+                .and_then(|rest| rest.strip_suffix('>'))
+                .map_or(AttrValue::Text(value), AttrValue::Html)
+        }
+
+        fn to_dot(self) -> String {
+            match self {
+                AttrValue::Text(text) => quote(text),
+                AttrValue::Html(html) => format!("<{}>", html),
+            }
+        }
+    }
+
+    /// Mark `content` as an HTML-like label, for use as an attribute
+    /// value: `with_attrs(&[("label", &html("<b>hi</b>"))])`.
+    pub fn html(content: &str) -> String {
+        format!("<{}>", content)
+    }
+
+    /// Render an attribute map as ` [k=v, ...]`, keys sorted so output
+    /// is deterministic, or nothing when there are no attributes.
+    pub(crate) fn attrs_to_dot(attrs: &std::collections::HashMap<String, String>) -> String {
+        if attrs.is_empty() {
+            return String::new();
+        }
+        let rendered = attrs
+            .iter()
+            .collect::<std::collections::BTreeMap<_, _>>()
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", quote(key), AttrValue::of(value).to_dot()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(" [{}]", rendered)
+    }
+
     pub mod graph_items {
         pub mod node {
             #[derive(Debug, Clone, PartialEq, Eq)]
@@ -26,6 +117,49 @@ pub mod graph {
                 pub fn get_attr(&self, attr: &str) -> Option<&str> {
                     self.attrs.get(attr).map(AsRef::as_ref)
                 }
+
+                pub fn set_attr(&mut self, key: &str, value: &str) {
+                    self.attrs.insert(key.to_owned(), value.to_owned());
+                }
+
+                pub fn remove_attr(&mut self, key: &str) -> Option<String> {
+                    self.attrs.remove(key)
+                }
+
+                /// Merge `attrs` into the existing attributes instead of
+                /// replacing them wholesale; later values win.
+                pub fn add_attrs(mut self, attrs: &[(&str, &str)]) -> Self {
+                    for &(key, value) in attrs {
+                        self.attrs.insert(key.to_owned(), value.to_owned());
+                    }
+                    self
+                }
+
+                /// Like [`Self::add_attrs`], but refuses to overwrite a
+                /// key that is already set.
+                pub fn try_add_attrs(
+                    mut self,
+                    attrs: &[(&str, &str)],
+                ) -> Result<Self, crate::graph::AttrConflict> {
+                    for &(key, value) in attrs {
+                        if self.attrs.contains_key(key) {
+                            return Err(crate::graph::AttrConflict(key.to_owned()));
+                        }
+                        self.attrs.insert(key.to_owned(), value.to_owned());
+                    }
+                    Ok(self)
+                }
+            }
+
+            impl std::fmt::Display for Node {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "{}{}",
+                        crate::graph::quote(&self.data),
+                        crate::graph::attrs_to_dot(&self.attrs)
+                    )
+                }
             }
         }
 
@@ -55,15 +189,94 @@ pub mod graph {
                         .collect();
                     self
                 }
+
+                /// The names of the two nodes this edge connects.
+                pub fn endpoints(&self) -> (&str, &str) {
+                    (&self.u.data, &self.v.data)
+                }
+
+                pub fn get_attr(&self, attr: &str) -> Option<&str> {
+                    self.attrs.get(attr).map(AsRef::as_ref)
+                }
+
+                pub fn set_attr(&mut self, key: &str, value: &str) {
+                    self.attrs.insert(key.to_owned(), value.to_owned());
+                }
+
+                pub fn remove_attr(&mut self, key: &str) -> Option<String> {
+                    self.attrs.remove(key)
+                }
+
+                /// Merge `attrs` into the existing attributes instead of
+                /// replacing them wholesale; later values win.
+                pub fn add_attrs(mut self, attrs: &[(&str, &str)]) -> Self {
+                    for &(key, value) in attrs {
+                        self.attrs.insert(key.to_owned(), value.to_owned());
+                    }
+                    self
+                }
+
+                /// Like [`Self::add_attrs`], but refuses to overwrite a
+                /// key that is already set.
+                pub fn try_add_attrs(
+                    mut self,
+                    attrs: &[(&str, &str)],
+                ) -> Result<Self, crate::graph::AttrConflict> {
+                    for &(key, value) in attrs {
+                        if self.attrs.contains_key(key) {
+                            return Err(crate::graph::AttrConflict(key.to_owned()));
+                        }
+                        self.attrs.insert(key.to_owned(), value.to_owned());
+                    }
+                    Ok(self)
+                }
+            }
+
+            impl std::fmt::Display for Edge {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "{} -- {}{}",
+                        crate::graph::quote(&self.u.data),
+                        crate::graph::quote(&self.v.data),
+                        crate::graph::attrs_to_dot(&self.attrs)
+                    )
+                }
             }
         }
     }
 
+    /// Addresses a graph element for attribute lookups.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Element<'a> {
+        Node(&'a str),
+        Edge(&'a str, &'a str),
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum GraphError {
+        UnknownNode(String),
+        /// The node still has edges attached; remove them first or use
+        /// [`Graph::remove_node_cascading`].
+        NodeHasEdges(String),
+        /// The edges contain a cycle; carries one, in order, with the
+        /// closing edge back to the first node implied.
+        Cycle(Vec<String>),
+    }
+
+    #[derive(Clone, Copy)]
+    enum Visit {
+        InProgress,
+        Done,
+    }
+
     #[derive(Debug, Clone)]
     pub struct Graph {
         pub nodes: Vec<graph_items::node::Node>,
         pub edges: Vec<graph_items::edge::Edge>,
         pub attrs: std::collections::HashMap<String, String>,
+        node_defaults: std::collections::HashMap<String, String>,
+        edge_defaults: std::collections::HashMap<String, String>,
     }
 
     impl Graph {
@@ -72,6 +285,8 @@ pub mod graph {
                 nodes: Default::default(),
                 edges: Default::default(),
                 attrs: Default::default(),
+                node_defaults: Default::default(),
+                edge_defaults: Default::default(),
             }
         }
 
@@ -93,8 +308,325 @@ pub mod graph {
             self
         }
 
+        /// Merge `attrs` into the existing attributes instead of
+        /// replacing them wholesale; later values win.
+        pub fn add_attrs(mut self, attrs: &[(&str, &str)]) -> Self {
+            for &(key, value) in attrs {
+                self.attrs.insert(key.to_owned(), value.to_owned());
+            }
+            self
+        }
+
+        /// Like [`Graph::add_attrs`], but refuses to overwrite a key
+        /// that is already set.
+        pub fn try_add_attrs(mut self, attrs: &[(&str, &str)]) -> Result<Self, AttrConflict> {
+            for &(key, value) in attrs {
+                if self.attrs.contains_key(key) {
+                    return Err(AttrConflict(key.to_owned()));
+                }
+                self.attrs.insert(key.to_owned(), value.to_owned());
+            }
+            Ok(self)
+        }
+
+        /// Defaults applied to every node, DOT's `node [shape=box]`
+        /// statement.
+        pub fn with_node_defaults(mut self, attrs: &[(&str, &str)]) -> Self {
+            self.node_defaults = attrs
+                .iter()
+                .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+                .collect();
+            self
+        }
+
+        /// Defaults applied to every edge, DOT's `edge [color=gray]`
+        /// statement.
+        pub fn with_edge_defaults(mut self, attrs: &[(&str, &str)]) -> Self {
+            self.edge_defaults = attrs
+                .iter()
+                .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+                .collect();
+            self
+        }
+
+        /// The attribute an element ends up with after defaults are
+        /// applied: its own attribute wins, then the node/edge defaults.
+        /// `None` for elements not in the graph.
+        pub fn effective_attr(&self, element: Element<'_>, key: &str) -> Option<&str> {
+            match element {
+                Element::Node(name) => {
+                    let node = self.get_node(name)?;
+                    node.get_attr(key)
+                        .or_else(|| self.node_defaults.get(key).map(AsRef::as_ref))
+                }
+                Element::Edge(u, v) => {
+                    let edge = self.get_edge(u, v)?;
+                    edge.get_attr(key)
+                        .or_else(|| self.edge_defaults.get(key).map(AsRef::as_ref))
+                }
+            }
+        }
+
         pub fn get_node(&self, key: &str) -> Option<&graph_items::node::Node> {
             self.nodes.iter().find(|&node| node.data == key)
         }
+
+        /// The edge from `u` to `v`, in that orientation.
+        pub fn get_edge(&self, u: &str, v: &str) -> Option<&graph_items::edge::Edge> {
+            self.edges.iter().find(|&edge| edge.endpoints() == (u, v))
+        }
+
+        /// Every edge touching `node`, at either endpoint.
+        pub fn edges_of<'a>(
+            &'a self,
+            node: &'a str,
+        ) -> impl Iterator<Item = &'a graph_items::edge::Edge> {
+            self.edges.iter().filter(move |edge| {
+                let (u, v) = edge.endpoints();
+                u == node || v == node
+            })
+        }
+
+        /// The nodes, in insertion order. Prefer this to reaching into
+        /// the public field; the field only stays public because the
+        /// canonical exercise tests poke at it.
+        pub fn nodes(&self) -> impl Iterator<Item = &graph_items::node::Node> {
+            self.nodes.iter()
+        }
+
+        /// The edges, in insertion order.
+        pub fn edges(&self) -> impl Iterator<Item = &graph_items::edge::Edge> {
+            self.edges.iter()
+        }
+
+        /// The other endpoint of every edge touching `node`. Parallel
+        /// edges yield their endpoint once each.
+        pub fn neighbors<'a>(&'a self, node: &'a str) -> impl Iterator<Item = &'a str> {
+            self.edges_of(node).map(move |edge| {
+                let (u, v) = edge.endpoints();
+                if u == node {
+                    v
+                } else {
+                    u
+                }
+            })
+        }
+
+        pub fn get_node_mut(&mut self, key: &str) -> Option<&mut graph_items::node::Node> {
+            self.nodes.iter_mut().find(|node| node.data == key)
+        }
+
+        pub fn get_edge_mut(&mut self, u: &str, v: &str) -> Option<&mut graph_items::edge::Edge> {
+            self.edges
+                .iter_mut()
+                .find(|edge| edge.endpoints() == (u, v))
+        }
+
+        pub fn add_node(&mut self, node: graph_items::node::Node) {
+            self.nodes.push(node);
+        }
+
+        /// Remove `name`, refusing while edges still touch it.
+        pub fn remove_node(&mut self, name: &str) -> Result<graph_items::node::Node, GraphError> {
+            if self.edges_of(name).next().is_some() {
+                return Err(GraphError::NodeHasEdges(name.to_owned()));
+            }
+            let index = self
+                .nodes
+                .iter()
+                .position(|node| node.data == name)
+                .ok_or_else(|| GraphError::UnknownNode(name.to_owned()))?;
+            Ok(self.nodes.remove(index))
+        }
+
+        /// Remove `name` along with every edge touching it, returning
+        /// both.
+        pub fn remove_node_cascading(
+            &mut self,
+            name: &str,
+        ) -> Result<(graph_items::node::Node, Vec<graph_items::edge::Edge>), GraphError> {
+            let index = self
+                .nodes
+                .iter()
+                .position(|node| node.data == name)
+                .ok_or_else(|| GraphError::UnknownNode(name.to_owned()))?;
+            let node = self.nodes.remove(index);
+            let (removed, kept) = std::mem::take(&mut self.edges)
+                .into_iter()
+                .partition(|edge| {
+                    let (u, v) = edge.endpoints();
+                    u == name || v == name
+                });
+            self.edges = kept;
+            Ok((node, removed))
+        }
+
+        pub fn add_edge(&mut self, edge: graph_items::edge::Edge) {
+            self.edges.push(edge);
+        }
+
+        pub fn remove_edge(&mut self, u: &str, v: &str) -> Option<graph_items::edge::Edge> {
+            let index = self
+                .edges
+                .iter()
+                .position(|edge| edge.endpoints() == (u, v))?;
+            Some(self.edges.remove(index))
+        }
+
+        pub fn set_attr(&mut self, key: &str, value: &str) {
+            self.attrs.insert(key.to_owned(), value.to_owned());
+        }
+
+        pub fn remove_attr(&mut self, key: &str) -> Option<String> {
+            self.attrs.remove(key)
+        }
+
+        /// Every node name in first-appearance order: declared nodes
+        /// first, then any endpoint only mentioned by an edge.
+        fn node_names(&self) -> Vec<&str> {
+            let mut names: Vec<&str> = Vec::new();
+            let mut seen = std::collections::HashSet::new();
+            let declared = self.nodes.iter().map(|node| node.data.as_str());
+            let endpoints = self.edges.iter().flat_map(|edge| {
+                let (u, v) = edge.endpoints();
+                [u, v]
+            });
+            for name in declared.chain(endpoints) {
+                if seen.insert(name) {
+                    names.push(name);
+                }
+            }
+            names
+        }
+
+        /// Order the nodes so every edge `u -- v` points forward, treating
+        /// the edge list as directed. Fails with the offending cycle if
+        /// there is one.
+        pub fn topo_sort(&self) -> Result<Vec<&str>, GraphError> {
+            let names = self.node_names();
+            let mut indegree: std::collections::HashMap<&str, usize> =
+                names.iter().map(|&name| (name, 0)).collect();
+            for edge in &self.edges {
+                *indegree
+                    .get_mut(edge.endpoints().1)
+                    .expect("endpoint known") += 1;
+            }
+            let mut sorted = Vec::with_capacity(names.len());
+            let mut ready: Vec<&str> = names
+                .iter()
+                .copied()
+                .filter(|name| indegree[name] == 0)
+                .collect();
+            while let Some(name) = ready.pop() {
+                sorted.push(name);
+                for edge in &self.edges {
+                    let (u, v) = edge.endpoints();
+                    if u == name {
+                        let remaining = indegree.get_mut(v).expect("endpoint known");
+                        *remaining -= 1;
+                        if *remaining == 0 {
+                            ready.push(v);
+                        }
+                    }
+                }
+            }
+            if sorted.len() == names.len() {
+                Ok(sorted)
+            } else {
+                let cycle = self.find_cycle().expect("unsorted nodes imply a cycle");
+                Err(GraphError::Cycle(
+                    cycle.into_iter().map(str::to_owned).collect(),
+                ))
+            }
+        }
+
+        /// A directed cycle through the edge list, if any, in order; the
+        /// closing edge back to the first node is implied.
+        pub fn find_cycle(&self) -> Option<Vec<&str>> {
+            let mut state = std::collections::HashMap::new();
+            let mut stack = Vec::new();
+            self.node_names()
+                .into_iter()
+                .find_map(|name| self.cycle_from(name, &mut state, &mut stack))
+        }
+
+        fn cycle_from<'a>(
+            &'a self,
+            node: &'a str,
+            state: &mut std::collections::HashMap<&'a str, Visit>,
+            stack: &mut Vec<&'a str>,
+        ) -> Option<Vec<&'a str>> {
+            if state.contains_key(node) {
+                return None;
+            }
+            state.insert(node, Visit::InProgress);
+            stack.push(node);
+            for edge in &self.edges {
+                let (u, v) = edge.endpoints();
+                if u != node {
+                    continue;
+                }
+                match state.get(v) {
+                    Some(Visit::InProgress) => {
+                        let start = stack.iter().position(|&name| name == v).expect("on stack");
+                        return Some(stack[start..].to_vec());
+                    }
+                    Some(Visit::Done) => {}
+                    None => {
+                        if let Some(cycle) = self.cycle_from(v, state, stack) {
+                            return Some(cycle);
+                        }
+                    }
+                }
+            }
+            stack.pop();
+            state.insert(node, Visit::Done);
+            None
+        }
+
+        /// Render the graph as Graphviz DOT text.
+        pub fn to_dot(&self) -> String {
+            self.to_string()
+        }
+    }
+
+    impl fmt::Display for Graph {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.nodes.is_empty()
+                && self.edges.is_empty()
+                && self.attrs.is_empty()
+                && self.node_defaults.is_empty()
+                && self.edge_defaults.is_empty()
+            {
+                return write!(f, "graph {{}}");
+            }
+            writeln!(f, "graph {{")?;
+            for (key, value) in self
+                .attrs
+                .iter()
+                .collect::<std::collections::BTreeMap<_, _>>()
+            {
+                writeln!(f, "    {}={};", quote(key), AttrValue::of(value).to_dot())?;
+            }
+            if !self.node_defaults.is_empty() {
+                writeln!(f, "    node{};", attrs_to_dot(&self.node_defaults))?;
+            }
+            if !self.edge_defaults.is_empty() {
+                writeln!(f, "    edge{};", attrs_to_dot(&self.edge_defaults))?;
+            }
+            for node in &self.nodes {
+                writeln!(f, "    {};", node)?;
+            }
+            for edge in &self.edges {
+                writeln!(f, "    {};", edge)?;
+            }
+            write!(f, "}}")
+        }
+    }
+
+    impl Default for Graph {
+        fn default() -> Self {
+            Self::new()
+        }
     }
 }
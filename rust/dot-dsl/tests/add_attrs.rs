@@ -0,0 +1,49 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    AttrConflict, Graph,
+};
+
+#[test]
+fn add_attrs_keeps_what_with_attrs_would_wipe() {
+    let graph = Graph::new()
+        .with_attrs(&[("bgcolor", "red")])
+        .add_attrs(&[("rankdir", "LR")]);
+    assert_eq!(graph.attrs.len(), 2);
+    assert_eq!(graph.attrs.get("bgcolor").map(String::as_str), Some("red"));
+}
+
+#[test]
+fn add_attrs_lets_later_values_win() {
+    let graph = Graph::new()
+        .with_attrs(&[("bgcolor", "red")])
+        .add_attrs(&[("bgcolor", "blue")]);
+    assert_eq!(graph.attrs.get("bgcolor").map(String::as_str), Some("blue"));
+}
+
+#[test]
+fn try_add_attrs_reports_the_conflicting_key() {
+    let result = Graph::new()
+        .with_attrs(&[("bgcolor", "red")])
+        .try_add_attrs(&[("rankdir", "LR"), ("bgcolor", "blue")]);
+    assert_eq!(result.unwrap_err(), AttrConflict("bgcolor".to_owned()));
+}
+
+#[test]
+fn nodes_merge_and_check_the_same_way() {
+    let node = Node::new("a")
+        .with_attrs(&[("color", "green")])
+        .add_attrs(&[("shape", "box")]);
+    assert_eq!(node.get_attr("color"), Some("green"));
+    assert_eq!(node.get_attr("shape"), Some("box"));
+    assert!(node.try_add_attrs(&[("shape", "circle")]).is_err());
+}
+
+#[test]
+fn edges_merge_and_check_the_same_way() {
+    let edge = Edge::new("a", "b")
+        .with_attrs(&[("weight", "1")])
+        .add_attrs(&[("label", "x")]);
+    assert_eq!(edge.get_attr("label"), Some("x"));
+    let conflict = edge.try_add_attrs(&[("weight", "2")]).unwrap_err();
+    assert_eq!(conflict, AttrConflict("weight".to_owned()));
+}
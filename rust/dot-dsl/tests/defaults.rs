@@ -0,0 +1,52 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    Element, Graph,
+};
+
+#[test]
+fn default_statements_are_emitted() {
+    let graph = Graph::new()
+        .with_node_defaults(&[("shape", "box")])
+        .with_edge_defaults(&[("color", "gray")])
+        .with_nodes(&[Node::new("a")]);
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    node [shape=box];\n    edge [color=gray];\n    a;\n}"
+    );
+}
+
+#[test]
+fn an_elements_own_attr_wins_over_the_default() {
+    let graph = Graph::new()
+        .with_node_defaults(&[("shape", "box")])
+        .with_nodes(&[
+            Node::new("plain"),
+            Node::new("round").with_attrs(&[("shape", "circle")]),
+        ]);
+    assert_eq!(
+        graph.effective_attr(Element::Node("plain"), "shape"),
+        Some("box")
+    );
+    assert_eq!(
+        graph.effective_attr(Element::Node("round"), "shape"),
+        Some("circle")
+    );
+}
+
+#[test]
+fn edge_defaults_resolve_too() {
+    let graph = Graph::new()
+        .with_edge_defaults(&[("color", "gray")])
+        .with_edges(&[Edge::new("a", "b")]);
+    assert_eq!(
+        graph.effective_attr(Element::Edge("a", "b"), "color"),
+        Some("gray")
+    );
+    assert_eq!(graph.effective_attr(Element::Edge("a", "b"), "style"), None);
+}
+
+#[test]
+fn unknown_elements_resolve_to_nothing() {
+    let graph = Graph::new().with_node_defaults(&[("shape", "box")]);
+    assert_eq!(graph.effective_attr(Element::Node("ghost"), "shape"), None);
+}
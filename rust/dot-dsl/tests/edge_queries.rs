@@ -0,0 +1,36 @@
+use dot_dsl::graph::{graph_items::edge::Edge, Graph};
+
+fn sample() -> Graph {
+    Graph::new().with_edges(&[
+        Edge::new("a", "b").with_attrs(&[("weight", "2")]),
+        Edge::new("b", "c"),
+        Edge::new("d", "b"),
+    ])
+}
+
+#[test]
+fn get_edge_matches_orientation() {
+    let graph = sample();
+    assert!(graph.get_edge("a", "b").is_some());
+    assert!(graph.get_edge("b", "a").is_none());
+}
+
+#[test]
+fn edge_attrs_are_queryable() {
+    let graph = sample();
+    let edge = graph.get_edge("a", "b").unwrap();
+    assert_eq!(edge.get_attr("weight"), Some("2"));
+    assert_eq!(edge.get_attr("color"), None);
+}
+
+#[test]
+fn edges_of_sees_both_endpoints() {
+    let graph = sample();
+    let incident: Vec<_> = graph.edges_of("b").map(Edge::endpoints).collect();
+    assert_eq!(incident, [("a", "b"), ("b", "c"), ("d", "b")]);
+}
+
+#[test]
+fn edges_of_an_unknown_node_is_empty() {
+    assert_eq!(sample().edges_of("zz").count(), 0);
+}
@@ -0,0 +1,36 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    Graph,
+};
+
+fn sample() -> Graph {
+    Graph::new()
+        .with_nodes(&[Node::new("a"), Node::new("b"), Node::new("c")])
+        .with_edges(&[Edge::new("a", "b"), Edge::new("c", "a")])
+}
+
+#[test]
+fn nodes_iterates_in_insertion_order() {
+    let names: Vec<_> = sample().nodes().map(|node| node.data.clone()).collect();
+    assert_eq!(names, ["a", "b", "c"]);
+}
+
+#[test]
+fn edges_iterates_in_insertion_order() {
+    let graph = sample();
+    let endpoints: Vec<_> = graph.edges().map(Edge::endpoints).collect();
+    assert_eq!(endpoints, [("a", "b"), ("c", "a")]);
+}
+
+#[test]
+fn neighbors_sees_both_edge_directions() {
+    let graph = sample();
+    let neighbors: Vec<_> = graph.neighbors("a").collect();
+    assert_eq!(neighbors, ["b", "c"]);
+}
+
+#[test]
+fn a_lonely_node_has_no_neighbors() {
+    assert_eq!(sample().neighbors("b").count(), 1);
+    assert_eq!(sample().neighbors("isolated").count(), 0);
+}
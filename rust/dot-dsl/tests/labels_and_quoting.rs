@@ -0,0 +1,40 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    html, Graph,
+};
+
+#[test]
+fn html_labels_keep_their_angle_brackets() {
+    let graph =
+        Graph::new().with_nodes(&[Node::new("a").with_attrs(&[("label", &html("<b>bold</b>"))])]);
+    assert_eq!(graph.to_dot(), "graph {\n    a [label=<<b>bold</b>>];\n}");
+}
+
+#[test]
+fn keywords_are_quoted_even_when_alphanumeric() {
+    let graph = Graph::new().with_edges(&[Edge::new("node", "Graph")]);
+    assert_eq!(graph.to_dot(), "graph {\n    \"node\" -- \"Graph\";\n}");
+}
+
+#[test]
+fn unicode_identifiers_stand_bare() {
+    let graph = Graph::new().with_nodes(&[Node::new("café"), Node::new("数")]);
+    assert_eq!(graph.to_dot(), "graph {\n    café;\n    数;\n}");
+}
+
+#[test]
+fn punctuation_still_forces_quoting() {
+    let graph = Graph::new().with_nodes(&[Node::new("a.b")]);
+    assert_eq!(graph.to_dot(), "graph {\n    \"a.b\";\n}");
+}
+
+#[test]
+fn html_values_survive_characters_that_text_would_escape() {
+    let graph = Graph::new().with_nodes(&[
+        Node::new("t").with_attrs(&[("label", &html(r#"<table border="1"></table>"#))])
+    ]);
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    t [label=<<table border=\"1\"></table>>];\n}"
+    );
+}
@@ -0,0 +1,76 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    Graph, GraphError,
+};
+
+#[test]
+fn nodes_and_edges_can_be_added_incrementally() {
+    let mut graph = Graph::new();
+    graph.add_node(Node::new("a"));
+    graph.add_node(Node::new("b"));
+    graph.add_edge(Edge::new("a", "b"));
+    assert!(graph.get_node("b").is_some());
+    assert!(graph.get_edge("a", "b").is_some());
+}
+
+#[test]
+fn removing_a_connected_node_errors() {
+    let mut graph = Graph::new();
+    graph.add_node(Node::new("a"));
+    graph.add_edge(Edge::new("a", "b"));
+    assert_eq!(
+        graph.remove_node("a"),
+        Err(GraphError::NodeHasEdges("a".to_owned()))
+    );
+    assert_eq!(
+        graph.remove_node("zz"),
+        Err(GraphError::UnknownNode("zz".to_owned()))
+    );
+}
+
+#[test]
+fn cascading_removal_takes_the_edges_too() {
+    let mut graph = Graph::new();
+    graph.add_node(Node::new("hub"));
+    graph.add_edge(Edge::new("hub", "a"));
+    graph.add_edge(Edge::new("b", "hub"));
+    graph.add_edge(Edge::new("a", "b"));
+    let (node, removed) = graph.remove_node_cascading("hub").unwrap();
+    assert_eq!(node, Node::new("hub"));
+    assert_eq!(removed.len(), 2);
+    assert_eq!(graph.edges, vec![Edge::new("a", "b")]);
+}
+
+#[test]
+fn remove_edge_returns_the_edge() {
+    let mut graph = Graph::new();
+    graph.add_edge(Edge::new("a", "b"));
+    assert_eq!(graph.remove_edge("a", "b"), Some(Edge::new("a", "b")));
+    assert_eq!(graph.remove_edge("a", "b"), None);
+}
+
+#[test]
+fn attrs_are_editable_at_every_level() {
+    let mut graph = Graph::new();
+    graph.add_node(Node::new("a"));
+    graph.add_edge(Edge::new("a", "b"));
+    graph.set_attr("bgcolor", "blue");
+    graph.get_node_mut("a").unwrap().set_attr("shape", "box");
+    graph
+        .get_edge_mut("a", "b")
+        .unwrap()
+        .set_attr("weight", "3");
+    assert_eq!(graph.attrs.get("bgcolor").map(String::as_str), Some("blue"));
+    assert_eq!(graph.get_node("a").unwrap().get_attr("shape"), Some("box"));
+    assert_eq!(
+        graph.get_edge("a", "b").unwrap().get_attr("weight"),
+        Some("3")
+    );
+}
+
+#[test]
+fn removed_attrs_come_back_out() {
+    let mut graph = Graph::new().with_attrs(&[("bgcolor", "red")]);
+    assert_eq!(graph.remove_attr("bgcolor"), Some("red".to_owned()));
+    assert_eq!(graph.remove_attr("bgcolor"), None);
+}
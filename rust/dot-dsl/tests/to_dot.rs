@@ -0,0 +1,52 @@
+use dot_dsl::graph::{
+    graph_items::{edge::Edge, node::Node},
+    Graph,
+};
+
+#[test]
+fn empty_graph_renders_empty_braces() {
+    assert_eq!(Graph::new().to_dot(), "graph {}");
+}
+
+#[test]
+fn nodes_edges_and_attrs_all_appear() {
+    let graph = Graph::new()
+        .with_nodes(&[
+            Node::new("a").with_attrs(&[("color", "green")]),
+            Node::new("b"),
+        ])
+        .with_edges(&[Edge::new("a", "b").with_attrs(&[("label", "ok")])])
+        .with_attrs(&[("bgcolor", "yellow")]);
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    bgcolor=yellow;\n    a [color=green];\n    b;\n    a -- b [label=ok];\n}"
+    );
+}
+
+#[test]
+fn display_matches_to_dot() {
+    let graph = Graph::new().with_nodes(&[Node::new("solo")]);
+    assert_eq!(graph.to_string(), graph.to_dot());
+}
+
+#[test]
+fn attribute_keys_are_sorted_for_deterministic_output() {
+    let graph = Graph::new().with_attrs(&[("z", "1"), ("a", "2"), ("m", "3")]);
+    assert_eq!(graph.to_dot(), "graph {\n    a=2;\n    m=3;\n    z=1;\n}");
+}
+
+#[test]
+fn identifiers_with_spaces_are_quoted() {
+    let graph = Graph::new()
+        .with_nodes(&[Node::new("hello world").with_attrs(&[("label", "it's \"fine\"")])]);
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    \"hello world\" [label=\"it's \\\"fine\\\"\"];\n}"
+    );
+}
+
+#[test]
+fn numeric_leading_identifiers_are_quoted() {
+    let graph = Graph::new().with_edges(&[Edge::new("1st", "2nd")]);
+    assert_eq!(graph.to_dot(), "graph {\n    \"1st\" -- \"2nd\";\n}");
+}
@@ -0,0 +1,53 @@
+use dot_dsl::graph::{graph_items::edge::Edge, Graph, GraphError};
+
+fn position(order: &[&str], name: &str) -> usize {
+    order.iter().position(|&n| n == name).unwrap()
+}
+
+#[test]
+fn every_edge_points_forward_in_the_sort() {
+    let graph = Graph::new().with_edges(&[
+        Edge::new("cargo", "rustc"),
+        Edge::new("rustc", "llvm"),
+        Edge::new("cargo", "llvm"),
+    ]);
+    let order = graph.topo_sort().unwrap();
+    assert!(position(&order, "cargo") < position(&order, "rustc"));
+    assert!(position(&order, "rustc") < position(&order, "llvm"));
+}
+
+#[test]
+fn isolated_nodes_are_included() {
+    use dot_dsl::graph::graph_items::node::Node;
+    let graph = Graph::new()
+        .with_nodes(&[Node::new("lonely")])
+        .with_edges(&[Edge::new("a", "b")]);
+    let order = graph.topo_sort().unwrap();
+    assert_eq!(order.len(), 3);
+    assert!(order.contains(&"lonely"));
+}
+
+#[test]
+fn a_cycle_fails_the_sort_and_names_itself() {
+    let graph = Graph::new().with_edges(&[
+        Edge::new("a", "b"),
+        Edge::new("b", "c"),
+        Edge::new("c", "a"),
+    ]);
+    match graph.topo_sort() {
+        Err(GraphError::Cycle(cycle)) => assert_eq!(cycle, ["a", "b", "c"]),
+        other => panic!("expected a cycle, got {:?}", other),
+    }
+}
+
+#[test]
+fn find_cycle_on_an_acyclic_graph_is_none() {
+    let graph = Graph::new().with_edges(&[Edge::new("a", "b"), Edge::new("a", "c")]);
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn a_self_loop_is_a_cycle() {
+    let graph = Graph::new().with_edges(&[Edge::new("me", "me")]);
+    assert_eq!(graph.find_cycle(), Some(vec!["me"]));
+}
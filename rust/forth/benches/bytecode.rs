@@ -0,0 +1,39 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use forth::Forth;
+
+/// A loop-heavy program: deep chains of word calls so most of the time is
+/// spent in the evaluator's dispatch loop rather than in parsing.
+fn call_chain_interpreter() -> Forth {
+    let mut f = Forth::with_limits(usize::MAX, usize::MAX);
+    f.eval(": w0 1 2 + DUP * DROP ;").unwrap();
+    for level in 1..200 {
+        f.eval(&format!(": w{} w{} w{} ;", level, level - 1, level - 1))
+            .unwrap();
+    }
+    f
+}
+
+fn builtin_heavy(c: &mut Criterion) {
+    let mut f = Forth::new();
+    f.eval(": churn 1 2 3 ROT SWAP OVER + * - DUP MAX DUP MIN DROP ;")
+        .unwrap();
+    c.bench_function("builtin_heavy_word", |b| {
+        b.iter(|| f.eval("churn churn churn churn").unwrap())
+    });
+}
+
+fn deep_call_chain(c: &mut Criterion) {
+    let mut f = call_chain_interpreter();
+    c.bench_function("deep_call_chain", |b| b.iter(|| f.eval("w16").unwrap()));
+}
+
+fn recursion(c: &mut Criterion) {
+    let mut f = Forth::with_limits(usize::MAX, 5_000);
+    f.eval(": nest 1 DROP nest ;").unwrap();
+    c.bench_function("recursion_to_depth_limit", |b| {
+        b.iter(|| f.eval("nest").unwrap_err())
+    });
+}
+
+criterion_group!(benches, builtin_heavy, deep_call_chain, recursion);
+criterion_main!(benches);
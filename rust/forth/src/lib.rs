@@ -1,17 +1,69 @@
 use nom::{
     branch::alt,
-    bytes::complete::tag_no_case,
-    character::complete::{alpha1, char, digit1, one_of, satisfy, space0, space1},
-    combinator::{map, map_res, opt, recognize, value},
-    multi::{many0, separated_list1},
-    sequence::{delimited, preceded, tuple},
+    bytes::complete::{tag, tag_no_case, take_till, take_until},
+    character::complete::{alpha1, char, digit1, multispace1, one_of, satisfy},
+    combinator::{map, map_res, not, opt, peek, recognize, value},
+    multi::{many0, many1, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
     IResult,
 };
 use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::io;
+use std::rc::Rc;
 
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(all(feature = "cell64", feature = "cell128"))]
+compile_error!("features `cell64` and `cell128` are mutually exclusive");
+
+/// The stack cell type: `i32` by default, widened by the `cell64` or
+/// `cell128` features for programs that need more headroom.
+#[cfg(not(any(feature = "cell64", feature = "cell128")))]
 pub type Value = i32;
+#[cfg(feature = "cell64")]
+pub type Value = i64;
+#[cfg(feature = "cell128")]
+pub type Value = i128;
+
+/// The unsigned counterpart of [`Value`], for magnitude arithmetic.
+#[cfg(not(any(feature = "cell64", feature = "cell128")))]
+type UValue = u32;
+#[cfg(feature = "cell64")]
+type UValue = u64;
+#[cfg(feature = "cell128")]
+type UValue = u128;
+
 pub type ForthResult = Result<(), Error>;
 
+/// Sum type for output operations
+#[derive(Debug, Copy, Clone)]
+enum OutputOp {
+    /// `.`: pop and print the top of the stack
+    Print,
+    /// `.S`: print the whole stack without consuming it
+    PrintStack,
+    /// `EMIT`: pop and print the character with that code point
+    Emit,
+    /// `CR`: print a newline
+    Cr,
+}
+
+/// Sum type for return-stack operations
+#[derive(Debug, Copy, Clone)]
+enum ReturnOp {
+    /// `>R`: move the top of the data stack to the return stack
+    ToR,
+    /// `R>`: move the top of the return stack to the data stack
+    RFrom,
+    /// `R@`: copy the top of the return stack to the data stack
+    RFetch,
+}
+
 /// Sum type for arithmetic operations
 #[derive(Debug, Copy, Clone)]
 enum ArithOp {
@@ -19,27 +71,128 @@ enum ArithOp {
     Sub,
     Mul,
     Div,
+    Mod,
+    Min,
+    Max,
 }
 
 impl ArithOp {
-    /// Evaluate the operation given `lhs` and `rhs` input values.
+    /// Evaluate the operation given `lhs` and `rhs` input values. All
+    /// operations are overflow-checked: results that don't fit in a cell
+    /// are an error rather than a silent wrap.
     fn eval(&self, lhs: Value, rhs: Value) -> Result<Value, Error> {
         Ok(match self {
-            ArithOp::Add => lhs + rhs,
-            ArithOp::Sub => lhs - rhs,
-            ArithOp::Mul => lhs * rhs,
-            ArithOp::Div => lhs.checked_div(rhs).ok_or(Error::DivisionByZero)?,
+            ArithOp::Add => lhs.checked_add(rhs).ok_or(Error::Overflow)?,
+            ArithOp::Sub => lhs.checked_sub(rhs).ok_or(Error::Overflow)?,
+            ArithOp::Mul => lhs.checked_mul(rhs).ok_or(Error::Overflow)?,
+            ArithOp::Div => checked_division(lhs.checked_div(rhs), rhs)?,
+            ArithOp::Mod => checked_division(lhs.checked_rem(rhs), rhs)?,
+            ArithOp::Min => lhs.min(rhs),
+            ArithOp::Max => lhs.max(rhs),
         })
     }
 }
 
+/// Sum type for float-stack operations
+#[derive(Debug, Copy, Clone)]
+enum FloatOp {
+    /// `F+`, `F-`, `F*`, `F/`: arithmetic on the top two floats. IEEE
+    /// semantics apply, so `F/` by zero yields an infinity rather than an
+    /// error.
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// `F.`: pop and print the top of the float stack
+    Print,
+    /// `FDUP`, `FDROP`, `FSWAP`, `FOVER`: float-stack shuffling
+    Dup,
+    Drop,
+    Swap,
+    Over,
+}
+
+/// Sum type for numeric-base operations
+#[derive(Debug, Copy, Clone)]
+enum BaseOp {
+    /// `HEX`: switch number parsing and printing to base 16
+    Hex,
+    /// `DECIMAL`: switch back to base 10
+    Decimal,
+    /// `BASE`: push the current base
+    Push,
+}
+
+/// Sum type for data-space memory operations
+#[derive(Debug, Copy, Clone)]
+enum MemoryOp {
+    /// `HERE`: push the next free data-space address
+    Here,
+    /// `ALLOT`: grow (or, for negative counts, shrink) the data space
+    Allot,
+    /// `CELLS`: convert a cell count to an address offset. Data space is
+    /// cell-addressed, so this is the identity; it exists so the standard
+    /// `n CELLS ALLOT` idiom reads naturally.
+    Cells,
+    /// `@`: fetch the cell at an address
+    Fetch,
+    /// `!`: store a value at an address
+    Store,
+    /// `+!`: add a value to the cell at an address
+    PlusStore,
+}
+
 #[derive(Debug, Copy, Clone)]
 enum BuiltinOp {
     Dup,
     Drop,
     Swap,
     Over,
+    Rot,
+    Nip,
+    Tuck,
+    TwoDup,
+    TwoDrop,
+    TwoSwap,
+    Negate,
+    Abs,
+    DivMod,
     Arith(ArithOp),
+    Output(OutputOp),
+    Return(ReturnOp),
+    Float(FloatOp),
+    Memory(MemoryOp),
+    Base(BaseOp),
+}
+
+/// An [`Error`] located in the source file it came from
+#[cfg(feature = "std")]
+#[derive(Debug, PartialEq)]
+pub struct FileError {
+    /// The file that was being evaluated
+    pub path: PathBuf,
+    /// The 1-based line of the statement that failed, or 0 when the file
+    /// itself could not be loaded
+    pub line: usize,
+    /// What went wrong
+    pub error: Error,
+}
+
+/// One slot in the dictionary
+#[derive(Debug, Clone)]
+struct DictEntry {
+    /// The name the slot was defined under
+    name: String,
+    /// How many stored strings existed when the slot was defined, so
+    /// `FORGET` can roll the string storage back along with it
+    strings_len: usize,
+    /// Whether the word executes at compile time when used inside a
+    /// definition (set by `IMMEDIATE`)
+    immediate: bool,
+    /// The definition body as parsed, kept for image serialization
+    exprs: Rc<Vec<Expr>>,
+    /// The body lowered to bytecode, which is what actually runs
+    code: Rc<Vec<Instr>>,
 }
 
 /// The result of parsing a definition
@@ -55,7 +208,118 @@ struct ParsedDefinition {
 #[derive(Debug, Clone)]
 enum Expr {
     Value(Value),
+    /// A numeric token, converted in whatever base is current when it is
+    /// compiled or evaluated
+    Number(String),
+    /// A float literal, pushed onto the float stack
+    FloatValue(f64),
     Symbol(String),
+    /// `." ..."`: print the text when evaluated
+    Print(String),
+    /// `S" ..."`: store the text and push its address and length
+    StringLit(String),
+    /// `RECURSE`: re-invoke the word currently being defined
+    Recurse,
+    /// A resolved reference to a dictionary slot
+    Call(usize),
+    /// `MARKER name`: executing it defines `name` as a rollback word
+    MakeMarker(String),
+    /// `INCLUDE "file"`: evaluate another source file in place
+    #[cfg(feature = "std")]
+    Include(String),
+    /// `FORGET name`: drop the word and everything defined after it
+    Forget(String),
+    /// The body of a marker word: executing it rolls the dictionary and
+    /// string storage back to the recorded lengths
+    Marker {
+        definitions_len: usize,
+        strings_len: usize,
+    },
+}
+
+/// One entry of the evaluator's explicit call stack: a compiled body and
+/// the index of the next instruction to run in it
+struct Frame {
+    code: Rc<Vec<Instr>>,
+    pc: usize,
+}
+
+/// One bytecode instruction. Definition bodies are lowered from [`Expr`]
+/// to this form when they are installed: builtins become opcodes instead
+/// of strings and calls are dictionary indices, so the evaluator's hot
+/// loop never re-parses or string-matches anything.
+#[derive(Debug, Clone)]
+enum Instr {
+    /// Push a cell
+    Push(Value),
+    /// Convert a numeric token in the current base and push it
+    Number(String),
+    /// Push a float
+    PushFloat(f64),
+    /// Write text to the output sink
+    Print(String),
+    /// Store a string and push its address and length
+    StringLit(String),
+    /// Re-invoke the current body one frame deeper
+    Recurse,
+    /// Invoke a dictionary slot
+    Call(usize),
+    /// Execute a builtin directly
+    Builtin(BuiltinOp),
+    /// A name still unresolved at compile time, looked up when executed
+    Late(String),
+    /// Define a rollback word
+    MakeMarker(String),
+    /// Drop a word and everything defined after it
+    Forget(String),
+    /// Roll the dictionary back to the recorded lengths
+    Marker {
+        definitions_len: usize,
+        strings_len: usize,
+    },
+    /// Evaluate another source file
+    #[cfg(feature = "std")]
+    Include(String),
+}
+
+/// Lower expressions to bytecode. `resolve_builtins` is set for definition
+/// bodies, binding builtin names to opcodes at compile time; top-level
+/// statements keep them late-bound so words (re)defined or forgotten
+/// mid-statement behave as they always have.
+fn compile(exprs: &[Expr], resolve_builtins: bool) -> Vec<Instr> {
+    exprs
+        .iter()
+        .map(|expr| match expr {
+            Expr::Value(value) => Instr::Push(*value),
+            Expr::Number(digits) => Instr::Number(digits.clone()),
+            Expr::FloatValue(value) => Instr::PushFloat(*value),
+            Expr::Print(text) => Instr::Print(text.clone()),
+            Expr::StringLit(text) => Instr::StringLit(text.clone()),
+            Expr::Recurse => Instr::Recurse,
+            Expr::Call(slot) => Instr::Call(*slot),
+            Expr::MakeMarker(name) => Instr::MakeMarker(name.clone()),
+            Expr::Forget(name) => Instr::Forget(name.clone()),
+            Expr::Marker {
+                definitions_len,
+                strings_len,
+            } => Instr::Marker {
+                definitions_len: *definitions_len,
+                strings_len: *strings_len,
+            },
+            #[cfg(feature = "std")]
+            Expr::Include(path) => Instr::Include(path.clone()),
+            Expr::Symbol(symbol) => {
+                if resolve_builtins && symbol != "immediate" {
+                    if let Ok((_, op)) = parse_builtin_op(symbol) {
+                        if Forth::BUILTIN_OPS.contains(&symbol.as_str()) {
+                            return Instr::Builtin(op);
+                        }
+                    }
+                }
+                Instr::Late(symbol.clone())
+            }
+        })
+        .collect()
 }
 
 /// The result of parsing a statement
@@ -71,17 +335,74 @@ enum Stmt {
 pub struct Forth {
     /// Current evaluated values
     stack: Vec<Value>,
-    /// The names visible to the interpreter
-    env: HashMap<String, Definition>,
+    /// The names visible to the interpreter, each pointing at its slot in
+    /// the dictionary
+    env: HashMap<String, usize>,
+    /// The dictionary: immutable definition bodies, appended in definition
+    /// order and referenced by index. Redefining a word appends a new slot
+    /// and repoints the name, leaving old slots (and the words resolved
+    /// against them) untouched.
+    definitions: Vec<DictEntry>,
+    /// The return stack manipulated by `>R`, `R>` and `R@`
+    return_stack: Vec<Value>,
+    /// The float stack manipulated by the `F`-prefixed words
+    float_stack: Vec<f64>,
+    /// Storage area for `S"` string literals, addressed by index
+    strings: Vec<String>,
+    /// Cell-addressed data space managed by `HERE` and `ALLOT`
+    memory: Vec<Value>,
+    /// The base used to read numeric tokens and print values
+    base: u32,
+    /// Files currently being evaluated, used to reject include cycles
+    #[cfg(feature = "std")]
+    include_stack: Vec<PathBuf>,
+    /// Maximum data/return stack height before evaluation fails
+    max_stack: usize,
+    /// Maximum depth of nested word invocations
+    max_call_depth: usize,
+    /// Observer notified before each word executes, if any
+    tracer: Option<Box<dyn Tracer>>,
+    /// Where output words write to
+    output: Box<dyn io::Write>,
 }
 
-/// A ParsedDefinition together with its execution environment
-#[derive(Debug, Clone)]
-struct Definition {
-    /// The expressions making up the defintition
-    exprs: Vec<Expr>,
-    /// The environment visible at the time of definition
-    env: HashMap<String, Definition>,
+/// Observer of word execution for step debuggers, profilers, and teaching
+/// tools. The evaluator calls [`Tracer::on_word`] immediately before each
+/// word (builtin or user-defined) runs.
+pub trait Tracer {
+    /// `word` is the name being executed; `stack` is the data stack as the
+    /// word sees it, before it runs.
+    fn on_word(&mut self, word: &str, stack: &[Value]);
+}
+
+/// A parse failure, locating the offending token in the input so embedders
+/// can underline it. Converts to [`Error::InvalidWord`] for callers that
+/// only care about the coarse classification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset of the offending token
+    pub offset: usize,
+    /// The token parsing stopped at (empty at end of input)
+    pub token: String,
+}
+
+impl ParseError {
+    fn new(input: &str, remaining: &str) -> Self {
+        Self {
+            offset: input.len() - remaining.len(),
+            token: remaining
+                .split_whitespace()
+                .next()
+                .unwrap_or("")
+                .to_string(),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(_: ParseError) -> Self {
+        Error::InvalidWord
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -90,13 +411,149 @@ pub enum Error {
     StackUnderflow,
     UnknownWord,
     InvalidWord,
+    /// The output sink failed to accept a write
+    Io,
+    /// A recursive word exceeded the call-depth limit
+    RecursionLimit,
+    /// An arithmetic result does not fit in a cell
+    Overflow,
+    /// A stack grew past the configured height limit
+    StackOverflow,
+    /// A file `INCLUDE`d itself, directly or through other files
+    #[cfg(feature = "std")]
+    IncludeCycle,
+    /// An image passed to [`Forth::load_image`] is malformed or was saved
+    /// by an incompatible interpreter
+    InvalidImage,
+    /// A memory access outside the allotted data space
+    InvalidAddress,
+}
+
+/// Canonical lowercase name of a builtin, used for tracing
+fn builtin_name(op: BuiltinOp) -> &'static str {
+    match op {
+        BuiltinOp::Dup => "dup",
+        BuiltinOp::Drop => "drop",
+        BuiltinOp::Swap => "swap",
+        BuiltinOp::Over => "over",
+        BuiltinOp::Rot => "rot",
+        BuiltinOp::Nip => "nip",
+        BuiltinOp::Tuck => "tuck",
+        BuiltinOp::TwoDup => "2dup",
+        BuiltinOp::TwoDrop => "2drop",
+        BuiltinOp::TwoSwap => "2swap",
+        BuiltinOp::Negate => "negate",
+        BuiltinOp::Abs => "abs",
+        BuiltinOp::DivMod => "/mod",
+        BuiltinOp::Arith(ArithOp::Add) => "+",
+        BuiltinOp::Arith(ArithOp::Sub) => "-",
+        BuiltinOp::Arith(ArithOp::Mul) => "*",
+        BuiltinOp::Arith(ArithOp::Div) => "/",
+        BuiltinOp::Arith(ArithOp::Mod) => "mod",
+        BuiltinOp::Arith(ArithOp::Min) => "min",
+        BuiltinOp::Arith(ArithOp::Max) => "max",
+        BuiltinOp::Output(OutputOp::Print) => ".",
+        BuiltinOp::Output(OutputOp::PrintStack) => ".s",
+        BuiltinOp::Output(OutputOp::Emit) => "emit",
+        BuiltinOp::Output(OutputOp::Cr) => "cr",
+        BuiltinOp::Return(ReturnOp::ToR) => ">r",
+        BuiltinOp::Return(ReturnOp::RFrom) => "r>",
+        BuiltinOp::Return(ReturnOp::RFetch) => "r@",
+        BuiltinOp::Float(FloatOp::Add) => "f+",
+        BuiltinOp::Float(FloatOp::Sub) => "f-",
+        BuiltinOp::Float(FloatOp::Mul) => "f*",
+        BuiltinOp::Float(FloatOp::Div) => "f/",
+        BuiltinOp::Float(FloatOp::Print) => "f.",
+        BuiltinOp::Float(FloatOp::Dup) => "fdup",
+        BuiltinOp::Float(FloatOp::Drop) => "fdrop",
+        BuiltinOp::Float(FloatOp::Swap) => "fswap",
+        BuiltinOp::Float(FloatOp::Over) => "fover",
+        BuiltinOp::Memory(MemoryOp::Here) => "here",
+        BuiltinOp::Memory(MemoryOp::Allot) => "allot",
+        BuiltinOp::Memory(MemoryOp::Cells) => "cells",
+        BuiltinOp::Memory(MemoryOp::Fetch) => "@",
+        BuiltinOp::Memory(MemoryOp::Store) => "!",
+        BuiltinOp::Memory(MemoryOp::PlusStore) => "+!",
+        BuiltinOp::Base(BaseOp::Hex) => "hex",
+        BuiltinOp::Base(BaseOp::Decimal) => "decimal",
+        BuiltinOp::Base(BaseOp::Push) => "base",
+    }
+}
+
+/// Render a value in `base`, with lowercase digits and a leading `-` for
+/// negatives
+fn format_value(value: Value, base: u32) -> String {
+    if base == 10 {
+        return value.to_string();
+    }
+    let mut magnitude = value.unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        let digit = (magnitude % base as UValue) as u8;
+        let digit = char::from_digit(u32::from(digit), base).expect("digit is below the base");
+        digits.push(digit);
+        magnitude /= base as UValue;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    let sign = if value < 0 { "-" } else { "" };
+    let digits: String = digits.iter().rev().collect();
+    format!("{}{}", sign, digits)
 }
 
-/// Parse digit strings with optional `-` into Values.
-fn parse_number(input: &str) -> IResult<&str, Value> {
-    map_res(recognize(tuple((opt(char('-')), digit1))), |res| {
-        Value::from_str_radix(res, 10)
-    })(input)
+/// Distinguish the two ways checked division fails: a zero divisor and
+/// `Value::MIN / -1`, which overflows the cell.
+fn checked_division(result: Option<Value>, rhs: Value) -> Result<Value, Error> {
+    result.ok_or(if rhs == 0 {
+        Error::DivisionByZero
+    } else {
+        Error::Overflow
+    })
+}
+
+/// Parse a single comment: `( ... )` runs to the closing paren, `\` runs
+/// to the end of the line.
+fn parse_comment(input: &str) -> IResult<&str, ()> {
+    alt((
+        value((), delimited(char('('), take_until(")"), char(')'))),
+        value((), preceded(char('\\'), take_till(|c| c == '\n'))),
+    ))(input)
+}
+
+/// Parse at least one run of whitespace or comments separating tokens
+fn sep1(input: &str) -> IResult<&str, ()> {
+    value((), many1(alt((value((), multispace1), parse_comment))))(input)
+}
+
+/// Parse any amount of whitespace or comments
+fn sep0(input: &str) -> IResult<&str, ()> {
+    value((), many0(alt((value((), multispace1), parse_comment))))(input)
+}
+
+/// Parse digit strings with optional `-` into Values. Words like `2DUP`
+/// start with a digit, so a number must not be immediately followed by more
+/// word characters.
+fn parse_number(input: &str) -> IResult<&str, &str> {
+    terminated(
+        recognize(tuple((opt(char('-')), digit1))),
+        not(satisfy(|c| c.is_alphanumeric())),
+    )(input)
+}
+
+/// Parse a `$`-prefixed hex literal, which is hexadecimal regardless of
+/// the current base
+fn parse_hex_literal(input: &str) -> IResult<&str, Value> {
+    map_res(
+        preceded(
+            char('$'),
+            recognize(tuple((
+                opt(char('-')),
+                many1(satisfy(|c| c.is_ascii_hexdigit())),
+            ))),
+        ),
+        |digits| Value::from_str_radix(digits, 16),
+    )(input)
 }
 
 /// Parse builtin operators and functions
@@ -106,10 +563,53 @@ fn parse_builtin_op(input: &str) -> IResult<&str, BuiltinOp> {
         value(BuiltinOp::Drop, tag_no_case("drop")),
         value(BuiltinOp::Swap, tag_no_case("swap")),
         value(BuiltinOp::Over, tag_no_case("over")),
-        value(BuiltinOp::Arith(ArithOp::Add), char('+')),
-        value(BuiltinOp::Arith(ArithOp::Sub), char('-')),
-        value(BuiltinOp::Arith(ArithOp::Mul), char('*')),
-        value(BuiltinOp::Arith(ArithOp::Div), char('/')),
+        value(BuiltinOp::Output(OutputOp::PrintStack), tag_no_case(".s")),
+        value(BuiltinOp::Output(OutputOp::Print), char('.')),
+        value(BuiltinOp::Output(OutputOp::Emit), tag_no_case("emit")),
+        value(BuiltinOp::Output(OutputOp::Cr), tag_no_case("cr")),
+        value(BuiltinOp::Return(ReturnOp::ToR), tag_no_case(">r")),
+        value(BuiltinOp::Return(ReturnOp::RFrom), tag_no_case("r>")),
+        value(BuiltinOp::Return(ReturnOp::RFetch), tag_no_case("r@")),
+        alt((
+            value(BuiltinOp::Float(FloatOp::Dup), tag_no_case("fdup")),
+            value(BuiltinOp::Float(FloatOp::Drop), tag_no_case("fdrop")),
+            value(BuiltinOp::Float(FloatOp::Swap), tag_no_case("fswap")),
+            value(BuiltinOp::Float(FloatOp::Over), tag_no_case("fover")),
+            value(BuiltinOp::Float(FloatOp::Print), tag_no_case("f.")),
+            value(BuiltinOp::Float(FloatOp::Add), tag_no_case("f+")),
+            value(BuiltinOp::Float(FloatOp::Sub), tag_no_case("f-")),
+            value(BuiltinOp::Float(FloatOp::Mul), tag_no_case("f*")),
+            value(BuiltinOp::Float(FloatOp::Div), tag_no_case("f/")),
+        )),
+        alt((
+            value(BuiltinOp::Memory(MemoryOp::Here), tag_no_case("here")),
+            value(BuiltinOp::Memory(MemoryOp::Allot), tag_no_case("allot")),
+            value(BuiltinOp::Memory(MemoryOp::Cells), tag_no_case("cells")),
+            value(BuiltinOp::Memory(MemoryOp::PlusStore), tag("+!")),
+            value(BuiltinOp::Memory(MemoryOp::Fetch), char('@')),
+            value(BuiltinOp::Memory(MemoryOp::Store), char('!')),
+            value(BuiltinOp::Base(BaseOp::Hex), tag_no_case("hex")),
+            value(BuiltinOp::Base(BaseOp::Decimal), tag_no_case("decimal")),
+            value(BuiltinOp::Base(BaseOp::Push), tag_no_case("base")),
+        )),
+        alt((
+            value(BuiltinOp::Rot, tag_no_case("rot")),
+            value(BuiltinOp::Nip, tag_no_case("nip")),
+            value(BuiltinOp::Tuck, tag_no_case("tuck")),
+            value(BuiltinOp::TwoDup, tag_no_case("2dup")),
+            value(BuiltinOp::TwoDrop, tag_no_case("2drop")),
+            value(BuiltinOp::TwoSwap, tag_no_case("2swap")),
+            value(BuiltinOp::DivMod, tag_no_case("/mod")),
+            value(BuiltinOp::Arith(ArithOp::Mod), tag_no_case("mod")),
+            value(BuiltinOp::Arith(ArithOp::Min), tag_no_case("min")),
+            value(BuiltinOp::Arith(ArithOp::Max), tag_no_case("max")),
+            value(BuiltinOp::Negate, tag_no_case("negate")),
+            value(BuiltinOp::Abs, tag_no_case("abs")),
+            value(BuiltinOp::Arith(ArithOp::Add), char('+')),
+            value(BuiltinOp::Arith(ArithOp::Sub), char('-')),
+            value(BuiltinOp::Arith(ArithOp::Mul), char('*')),
+            value(BuiltinOp::Arith(ArithOp::Div), char('/')),
+        )),
     ))(input)
 }
 
@@ -117,8 +617,8 @@ fn parse_builtin_op(input: &str) -> IResult<&str, BuiltinOp> {
 fn parse_definition(input: &str) -> IResult<&str, ParsedDefinition> {
     map(
         tuple((
-            preceded(tuple((char(':'), space0)), parse_symbol),
-            delimited(space1, parse_expr, tuple((space0, char(';')))),
+            preceded(tuple((char(':'), sep0)), parse_symbol),
+            delimited(sep1, parse_expr, tuple((sep0, char(';')))),
         )),
         |(name, exprs)| ParsedDefinition {
             name: name.to_lowercase(),
@@ -130,75 +630,470 @@ fn parse_definition(input: &str) -> IResult<&str, ParsedDefinition> {
 /// Parse symbols: arithmetic operators or dash separated alphanumeric characters
 fn parse_symbol(input: &str) -> IResult<&str, &str> {
     alt((
+        recognize(tuple((char('.'), opt(one_of("sS"))))),
+        recognize(preceded(char('>'), one_of("rR"))),
+        recognize(tuple((one_of("rR"), one_of(">@")))),
+        recognize(preceded(char('/'), tag_no_case("mod"))),
+        recognize(tag("+!")),
         recognize(one_of("+-*/")),
+        recognize(one_of("[]")),
+        recognize(one_of("!@")),
+        recognize(tuple((one_of("fF"), one_of("+-*/.")))),
         recognize(tuple((
-            alpha1,
+            alt((alpha1, terminated(digit1, peek(alpha1)))),
             many0(satisfy(|c| c.is_alphanumeric() || c == '-' || c == '_')),
         ))),
     ))(input)
 }
 
-/// Parse a single expr: either a number or symbol
+/// Parse a string literal: `." ..."` prints, `S" ..."` stores. The text
+/// starts after the space following the opening quote and may itself
+/// contain spaces, so this runs before whitespace splitting.
+fn parse_string(input: &str) -> IResult<&str, Expr> {
+    alt((
+        map(
+            preceded(
+                tuple((tag_no_case(".\""), char(' '))),
+                terminated(take_until("\""), char('\"')),
+            ),
+            |text: &str| Expr::Print(text.to_string()),
+        ),
+        map(
+            preceded(
+                tuple((tag_no_case("s\""), char(' '))),
+                terminated(take_until("\""), char('\"')),
+            ),
+            |text: &str| Expr::StringLit(text.to_string()),
+        ),
+    ))(input)
+}
+
+/// Parse a single expr: a string literal, number or symbol
 fn parse_single_expr(input: &str) -> IResult<&str, Expr> {
+    #[cfg(feature = "std")]
+    {
+        if let Ok(parsed) = parse_include(input) {
+            return Ok(parsed);
+        }
+    }
     alt((
-        map(parse_number, Expr::Value),
+        parse_string,
+        parse_marker,
+        parse_forget,
+        map(parse_float, Expr::FloatValue),
+        map(parse_hex_literal, Expr::Value),
+        map(parse_number, |digits: &str| {
+            Expr::Number(digits.to_string())
+        }),
         map(parse_symbol, |string| Expr::Symbol(string.to_lowercase())),
     ))(input)
 }
 
 /// Parse a whitespace separated list of single expressions
 fn parse_expr(input: &str) -> IResult<&str, Vec<Expr>> {
-    separated_list1(space1, parse_single_expr)(input)
+    separated_list1(sep1, parse_single_expr)(input)
+}
+
+/// Parse a whole program, requiring every token to be consumed and
+/// reporting where parsing stopped otherwise
+fn parse_program(input: &str) -> Result<Vec<Stmt>, ParseError> {
+    match preceded(sep0, parse_stmts)(input) {
+        Ok((remaining, stmts)) => {
+            let (remaining, ()) = sep0(remaining).expect("sep0 always succeeds");
+            if remaining.is_empty() {
+                Ok(stmts)
+            } else {
+                Err(ParseError::new(input, remaining))
+            }
+        }
+        Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => {
+            Err(ParseError::new(input, error.input))
+        }
+        // Complete parsers never return Incomplete; treat it as end of input
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(input, "")),
+    }
+}
+
+/// Parse a float literal: digits on both sides of a `.`, e.g. `2.5`
+fn parse_float(input: &str) -> IResult<&str, f64> {
+    map_res(
+        recognize(tuple((opt(char('-')), digit1, char('.'), digit1))),
+        str::parse,
+    )(input)
+}
+
+/// Parse an `INCLUDE "file"` expression
+#[cfg(feature = "std")]
+fn parse_include(input: &str) -> IResult<&str, Expr> {
+    map(
+        delimited(
+            tuple((tag_no_case("include"), sep1, char('"'))),
+            take_until("\""),
+            char('"'),
+        ),
+        |path: &str| Expr::Include(path.to_string()),
+    )(input)
+}
+
+/// Parse a `MARKER name` expression
+fn parse_marker(input: &str) -> IResult<&str, Expr> {
+    map(
+        preceded(tuple((tag_no_case("marker"), sep1)), parse_symbol),
+        |name| Expr::MakeMarker(name.to_lowercase()),
+    )(input)
+}
+
+/// Parse a `FORGET name` expression
+fn parse_forget(input: &str) -> IResult<&str, Expr> {
+    map(
+        preceded(tuple((tag_no_case("forget"), sep1)), parse_symbol),
+        |name| Expr::Forget(name.to_lowercase()),
+    )(input)
+}
+
+/// Parse a single definition or run of expressions
+fn parse_stmt(input: &str) -> IResult<&str, Stmt> {
+    alt((
+        map(parse_definition, Stmt::ParsedDefinition),
+        map(parse_expr, Stmt::Exprs),
+    ))(input)
+}
+
+/// Parse a single definition or expression. Used for file evaluation,
+/// where keeping statements small makes error lines precise
+#[cfg(feature = "std")]
+fn parse_file_stmt(input: &str) -> IResult<&str, Stmt> {
+    alt((
+        map(parse_definition, Stmt::ParsedDefinition),
+        map(parse_single_expr, |expr| Stmt::Exprs(vec![expr])),
+    ))(input)
 }
 
 /// Parse a list of definitions or a list of expressions
 fn parse_stmts(input: &str) -> IResult<&str, Vec<Stmt>> {
-    separated_list1(
-        space1,
-        alt((
-            map(parse_definition, Stmt::ParsedDefinition),
-            map(parse_expr, Stmt::Exprs),
-        )),
-    )(input)
+    separated_list1(sep1, parse_stmt)(input)
 }
 
 impl Forth {
     /// Builtin operations
-    const BUILTIN_OPS: [&'static str; 8] = ["dup", "drop", "swap", "over", "+", "-", "*", "/"];
+    const BUILTIN_OPS: [&'static str; 45] = [
+        "dup", "drop", "swap", "over", "+", "-", "*", "/", ".", ".s", "emit", "cr", ">r", "r>",
+        "r@", "mod", "/mod", "negate", "abs", "min", "max", "rot", "nip", "tuck", "2dup", "2drop",
+        "2swap", "f+", "f-", "f*", "f/", "f.", "fdup", "fdrop", "fswap", "fover", "here", "allot",
+        "cells", "@", "!", "+!", "hex", "decimal", "base",
+    ];
 
     /// Construct a new
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
+        Self::with_output(io::stdout())
+    }
+
+    /// Construct an interpreter whose output words (`.`, `.S`, `EMIT`, `CR`)
+    /// write to `output` instead of stdout, so embedders can capture program
+    /// output.
+    pub fn with_output<W>(output: W) -> Self
+    where
+        W: io::Write + 'static,
+    {
         Self {
             stack: Default::default(),
             env: Default::default(),
+            definitions: Default::default(),
+            return_stack: Default::default(),
+            float_stack: Default::default(),
+            strings: Default::default(),
+            memory: Default::default(),
+            base: 10,
+            #[cfg(feature = "std")]
+            include_stack: Default::default(),
+            max_stack: usize::MAX,
+            max_call_depth: Self::DEFAULT_MAX_CALL_DEPTH,
+            tracer: None,
+            output: Box::new(output),
         }
     }
 
+    /// Construct an interpreter that fails with [`Error::StackOverflow`]
+    /// when either stack grows past `max_stack` values and with
+    /// [`Error::RecursionLimit`] when word invocations nest more than
+    /// `max_call_depth` deep, instead of exhausting host memory on
+    /// untrusted programs.
+    pub fn with_limits(max_stack: usize, max_call_depth: usize) -> Self {
+        Self {
+            max_stack,
+            max_call_depth,
+            ..Self::new()
+        }
+    }
+
+    /// Install an execution tracer, replacing any existing one
+    pub fn set_tracer<T>(&mut self, tracer: T)
+    where
+        T: Tracer + 'static,
+    {
+        self.tracer = Some(Box::new(tracer));
+    }
+
+    /// Remove the execution tracer, if any
+    pub fn clear_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Serialize the dictionary, data space, and stacks into a byte image
+    /// that [`Forth::load_image`] can restore, so user-defined words can
+    /// persist across sessions. The format records the cell width, so
+    /// images only load into interpreters built with the same cell type.
+    pub fn save_image(&self) -> Vec<u8> {
+        let mut image = Vec::new();
+        image.extend_from_slice(IMAGE_MAGIC);
+        image.push(IMAGE_VERSION);
+        image.push(std::mem::size_of::<Value>() as u8);
+        write_values(&mut image, &self.stack);
+        write_values(&mut image, &self.return_stack);
+        write_len(&mut image, self.float_stack.len());
+        for &float in self.float_stack.iter() {
+            image.extend_from_slice(&float.to_le_bytes());
+        }
+        write_len(&mut image, self.strings.len());
+        for string in self.strings.iter() {
+            write_str(&mut image, string);
+        }
+        write_values(&mut image, &self.memory);
+        write_len(&mut image, self.definitions.len());
+        for entry in self.definitions.iter() {
+            write_str(&mut image, &entry.name);
+            write_len(&mut image, entry.strings_len);
+            image.push(entry.immediate as u8);
+            write_len(&mut image, entry.exprs.len());
+            for expr in entry.exprs.iter() {
+                write_expr(&mut image, expr);
+            }
+        }
+        write_len(&mut image, self.env.len());
+        for (name, &slot) in self.env.iter() {
+            write_str(&mut image, name);
+            write_len(&mut image, slot);
+        }
+        image
+    }
+
+    /// Replace the dictionary, data space, and stacks with the contents of
+    /// an image produced by [`Forth::save_image`]. The output sink, tracer,
+    /// and limits are kept. Fails with [`Error::InvalidImage`] without
+    /// touching any state if the image is malformed or was saved with a
+    /// different cell width.
+    pub fn load_image(&mut self, image: &[u8]) -> ForthResult {
+        let mut reader = ImageReader { bytes: image };
+        if reader.take(IMAGE_MAGIC.len())? != IMAGE_MAGIC
+            || reader.read_u8()? != IMAGE_VERSION
+            || reader.read_u8()? as usize != std::mem::size_of::<Value>()
+        {
+            return Err(Error::InvalidImage);
+        }
+        let stack = reader.read_values()?;
+        let return_stack = reader.read_values()?;
+        let float_stack = (0..reader.read_len()?)
+            .map(|_| reader.read_f64())
+            .collect::<Result<Vec<_>, _>>()?;
+        let strings = (0..reader.read_len()?)
+            .map(|_| reader.read_str())
+            .collect::<Result<Vec<_>, _>>()?;
+        let memory = reader.read_values()?;
+        let definitions = (0..reader.read_len()?)
+            .map(|_| {
+                let name = reader.read_str()?;
+                let strings_len = reader.read_len()?;
+                let immediate = reader.read_u8()? != 0;
+                let exprs = (0..reader.read_len()?)
+                    .map(|_| reader.read_expr())
+                    .collect::<Result<Vec<_>, _>>()?;
+                let code = compile(&exprs, true);
+                Ok(DictEntry {
+                    name,
+                    strings_len,
+                    immediate,
+                    exprs: Rc::new(exprs),
+                    code: Rc::new(code),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let env = (0..reader.read_len()?)
+            .map(|_| Ok((reader.read_str()?, reader.read_len()?)))
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidImage);
+        }
+        // Reject dangling slot references rather than panicking later
+        let slot_in_bounds = |slot: &usize| *slot < definitions.len();
+        let expr_in_bounds = |expr: &Expr| match expr {
+            Expr::Call(slot) => slot_in_bounds(slot),
+            Expr::Marker {
+                definitions_len,
+                strings_len,
+            } => *definitions_len <= definitions.len() && *strings_len <= strings.len(),
+            _ => true,
+        };
+        let valid = env.values().all(slot_in_bounds)
+            && definitions
+                .iter()
+                .all(|entry| entry.exprs.iter().all(expr_in_bounds));
+        if !valid {
+            return Err(Error::InvalidImage);
+        }
+        self.stack = stack;
+        self.return_stack = return_stack;
+        self.float_stack = float_stack;
+        self.strings = strings;
+        self.memory = memory;
+        self.definitions = definitions;
+        self.env = env;
+        Ok(())
+    }
+
     /// Return the list of values currently available
     pub fn stack(&self) -> &[Value] {
         &self.stack
     }
 
+    /// Return the values currently on the float stack
+    pub fn float_stack(&self) -> &[f64] {
+        &self.float_stack
+    }
+
+    /// Resolve an address pushed by `S"` back to its text
+    pub fn string(&self, addr: Value) -> Option<&str> {
+        self.strings.get(addr as usize).map(String::as_str)
+    }
+
+    /// Check that `input` parses, without evaluating it. The error carries
+    /// the offset and text of the offending token.
+    pub fn check(input: &str) -> Result<(), ParseError> {
+        parse_program(input).map(|_| ())
+    }
+
     /// Evaluate the `input` expression
     pub fn eval(&mut self, input: &str) -> ForthResult {
-        let (_, stmts) = parse_stmts(input).map_err(|_| Error::InvalidWord)?;
+        let stmts = parse_program(input)?;
         for stmt in stmts.into_iter() {
-            match stmt {
-                Stmt::ParsedDefinition(ParsedDefinition { name, exprs }) => {
-                    self.env.insert(
-                        name.to_lowercase(),
-                        Definition {
-                            exprs,
-                            env: self.env.clone(),
+            self.eval_stmt(stmt)?;
+        }
+        Ok(())
+    }
+
+    /// Evaluate a Forth source file.
+    ///
+    /// Errors are reported against the file and the line of the statement
+    /// that raised them. A file may not `INCLUDE` itself, directly or
+    /// through other files; doing so fails with [`Error::IncludeCycle`].
+    #[cfg(feature = "std")]
+    pub fn eval_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), FileError> {
+        let path = path.as_ref();
+        let file_error = |line, error| FileError {
+            path: path.to_path_buf(),
+            line,
+            error,
+        };
+        let canonical = path.canonicalize().map_err(|_| file_error(0, Error::Io))?;
+        if self.include_stack.contains(&canonical) {
+            return Err(file_error(0, Error::IncludeCycle));
+        }
+        let source = fs::read_to_string(path).map_err(|_| file_error(0, Error::Io))?;
+        self.include_stack.push(canonical);
+        let result = self.eval_source(&source);
+        self.include_stack.pop();
+        result.map_err(|(offset, error)| {
+            file_error(source[..offset].matches('\n').count() + 1, error)
+        })
+    }
+
+    /// Evaluate `source` one statement at a time, reporting failures with
+    /// the byte offset of the statement that raised them
+    #[cfg(feature = "std")]
+    fn eval_source(&mut self, source: &str) -> Result<(), (usize, Error)> {
+        let (mut remaining, ()) = sep0(source).expect("sep0 always succeeds");
+        while !remaining.is_empty() {
+            let offset = source.len() - remaining.len();
+            let located = |error| (offset, error);
+            let (rest, stmt) =
+                parse_file_stmt(remaining).map_err(|_| located(Error::InvalidWord))?;
+            self.eval_stmt(stmt).map_err(located)?;
+            let (rest, ()) = sep0(rest).expect("sep0 always succeeds");
+            remaining = rest;
+        }
+        Ok(())
+    }
+
+    /// Evaluate a single parsed statement
+    fn eval_stmt(&mut self, stmt: Stmt) -> ForthResult {
+        match stmt {
+            Stmt::ParsedDefinition(ParsedDefinition { name, exprs }) => {
+                // `RECURSE` always means the word being defined. A bare
+                // self reference means the *previous* definition when
+                // one exists (that's how redefinition chains work), and
+                // the in-progress word otherwise, making recursion
+                // possible for fresh words. Every other name already in
+                // the dictionary is resolved to its slot now, so later
+                // redefinitions can't change this word's meaning;
+                // unknown names stay symbolic and are looked up when the
+                // word runs.
+                let fresh = !self.env.contains_key(&name);
+                let mut body = Vec::with_capacity(exprs.len());
+                // `[` flips the compiler into interpret state: expressions
+                // up to the matching `]` run now instead of being compiled
+                let mut interpreting = false;
+                for expr in exprs {
+                    match expr {
+                        Expr::Symbol(symbol) if symbol == "[" => interpreting = true,
+                        Expr::Symbol(symbol) if symbol == "]" => interpreting = false,
+                        expr if interpreting => {
+                            let code = compile(&[expr], false);
+                            self.eval_stack(Rc::new(code))?;
+                        }
+                        // `LITERAL` compiles the value on top of the stack
+                        // right now into the body
+                        Expr::Symbol(symbol) if symbol == "literal" => {
+                            let value = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                            body.push(Expr::Value(value));
+                        }
+                        Expr::Symbol(symbol)
+                            if symbol == "recurse" || (fresh && symbol == name) =>
+                        {
+                            body.push(Expr::Recurse);
+                        }
+                        // Numbers are converted with the base current at
+                        // compile time, as in standard Forth
+                        Expr::Number(digits) => {
+                            let value = Value::from_str_radix(&digits, self.base)
+                                .map_err(|_| Error::UnknownWord)?;
+                            body.push(Expr::Value(value));
+                        }
+                        Expr::Symbol(symbol) => match self.env.get(&symbol) {
+                            // Immediate words execute during compilation
+                            // instead of being compiled in
+                            Some(&slot) if self.definitions[slot].immediate => {
+                                let immediate_body = Rc::clone(&self.definitions[slot].code);
+                                self.eval_stack(immediate_body)?;
+                            }
+                            Some(&slot) => body.push(Expr::Call(slot)),
+                            // Not a word (yet); if it reads as a number in
+                            // the current base, compile it as one,
+                            // otherwise leave it late-bound
+                            None => match Value::from_str_radix(&symbol, self.base) {
+                                Ok(value) => body.push(Expr::Value(value)),
+                                Err(_) => body.push(Expr::Symbol(symbol)),
+                            },
                         },
-                    );
-                }
-                Stmt::Exprs(exprs) => {
-                    self.eval_stack(exprs, self.env.clone())?;
+                        expr => body.push(expr),
+                    }
                 }
-            };
-        }
+                self.define(name, body);
+            }
+            Stmt::Exprs(exprs) => {
+                let code = compile(&exprs, false);
+                self.eval_stack(Rc::new(code))?;
+            }
+        };
         Ok(())
     }
 
@@ -225,47 +1120,522 @@ impl Forth {
             BuiltinOp::Over => {
                 self.stack.push(self.stack[self.second_to_last_index()?]);
             }
+            BuiltinOp::Rot => {
+                let third_to_last_index = self
+                    .stack
+                    .len()
+                    .checked_sub(3)
+                    .ok_or(Error::StackUnderflow)?;
+                let third = self.stack.remove(third_to_last_index);
+                self.stack.push(third);
+            }
+            BuiltinOp::Nip => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                *self.stack.last_mut().ok_or(Error::StackUnderflow)? = top;
+            }
+            BuiltinOp::Tuck => {
+                let second_to_last_index = self.second_to_last_index()?;
+                let top = *self.stack.last().expect("at least two values");
+                self.stack.insert(second_to_last_index, top);
+            }
+            BuiltinOp::TwoDup => {
+                let second_to_last_index = self.second_to_last_index()?;
+                self.stack.extend_from_within(second_to_last_index..);
+            }
+            BuiltinOp::TwoDrop => {
+                let new_len = self
+                    .stack
+                    .len()
+                    .checked_sub(2)
+                    .ok_or(Error::StackUnderflow)?;
+                self.stack.truncate(new_len);
+            }
+            BuiltinOp::TwoSwap => {
+                let fourth_to_last_index = self
+                    .stack
+                    .len()
+                    .checked_sub(4)
+                    .ok_or(Error::StackUnderflow)?;
+                self.stack[fourth_to_last_index..].rotate_left(2);
+            }
+            BuiltinOp::Negate => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                self.stack.push(top.checked_neg().ok_or(Error::Overflow)?);
+            }
+            BuiltinOp::Abs => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                self.stack.push(top.checked_abs().ok_or(Error::Overflow)?);
+            }
+            BuiltinOp::DivMod => {
+                let rhs = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                let lhs = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                self.stack
+                    .push(checked_division(lhs.checked_rem(rhs), rhs)?);
+                self.stack
+                    .push(checked_division(lhs.checked_div(rhs), rhs)?);
+            }
             BuiltinOp::Arith(op) => {
                 let rhs = self.stack.pop().ok_or(Error::StackUnderflow)?;
                 let lhs = self.stack.pop().ok_or(Error::StackUnderflow)?;
                 self.stack.push(op.eval(lhs, rhs)?);
             }
+            BuiltinOp::Output(op) => self.eval_output_op(op)?,
+            BuiltinOp::Return(op) => self.eval_return_op(op)?,
+            BuiltinOp::Float(op) => self.eval_float_op(op)?,
+            BuiltinOp::Memory(op) => self.eval_memory_op(op)?,
+            BuiltinOp::Base(op) => match op {
+                BaseOp::Hex => self.base = 16,
+                BaseOp::Decimal => self.base = 10,
+                BaseOp::Push => self.stack.push(self.base as Value),
+            },
+        }
+        Ok(())
+    }
+
+    /// Evaluate a return-stack operation
+    fn eval_return_op(&mut self, op: ReturnOp) -> ForthResult {
+        match op {
+            ReturnOp::ToR => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                self.return_stack.push(top);
+            }
+            ReturnOp::RFrom => {
+                let top = self.return_stack.pop().ok_or(Error::StackUnderflow)?;
+                self.stack.push(top);
+            }
+            ReturnOp::RFetch => {
+                self.stack
+                    .push(*self.return_stack.last().ok_or(Error::StackUnderflow)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate a data-space memory operation
+    fn eval_memory_op(&mut self, op: MemoryOp) -> ForthResult {
+        match op {
+            MemoryOp::Here => self.stack.push(self.memory.len() as Value),
+            MemoryOp::Allot => {
+                let count = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                if count >= 0 {
+                    self.memory.resize(self.memory.len() + count as usize, 0);
+                } else {
+                    let new_len = self
+                        .memory
+                        .len()
+                        .checked_sub(count.unsigned_abs() as usize)
+                        .ok_or(Error::InvalidAddress)?;
+                    self.memory.truncate(new_len);
+                }
+            }
+            // Data space is cell-addressed, so a cell count already is an
+            // address offset; only the underflow check remains
+            MemoryOp::Cells => {
+                self.stack.last().ok_or(Error::StackUnderflow)?;
+            }
+            MemoryOp::Fetch => {
+                let addr = self.pop_address()?;
+                let value = *self.memory.get(addr).ok_or(Error::InvalidAddress)?;
+                self.stack.push(value);
+            }
+            MemoryOp::Store => {
+                let addr = self.pop_address()?;
+                let value = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                *self.memory.get_mut(addr).ok_or(Error::InvalidAddress)? = value;
+            }
+            MemoryOp::PlusStore => {
+                let addr = self.pop_address()?;
+                let value = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                let cell = self.memory.get_mut(addr).ok_or(Error::InvalidAddress)?;
+                *cell = cell.checked_add(value).ok_or(Error::Overflow)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pop a value and convert it to a data-space address
+    fn pop_address(&mut self) -> Result<usize, Error> {
+        let addr = self.stack.pop().ok_or(Error::StackUnderflow)?;
+        usize::try_from(addr).map_err(|_| Error::InvalidAddress)
+    }
+
+    /// Evaluate a float-stack operation
+    fn eval_float_op(&mut self, op: FloatOp) -> ForthResult {
+        match op {
+            FloatOp::Add | FloatOp::Sub | FloatOp::Mul | FloatOp::Div => {
+                let rhs = self.float_stack.pop().ok_or(Error::StackUnderflow)?;
+                let lhs = self.float_stack.pop().ok_or(Error::StackUnderflow)?;
+                self.float_stack.push(match op {
+                    FloatOp::Add => lhs + rhs,
+                    FloatOp::Sub => lhs - rhs,
+                    FloatOp::Mul => lhs * rhs,
+                    _ => lhs / rhs,
+                });
+            }
+            FloatOp::Print => {
+                let top = self.float_stack.pop().ok_or(Error::StackUnderflow)?;
+                write!(self.output, "{} ", top).map_err(|_| Error::Io)?;
+            }
+            FloatOp::Dup => {
+                self.float_stack
+                    .push(*self.float_stack.last().ok_or(Error::StackUnderflow)?);
+            }
+            FloatOp::Drop => {
+                self.float_stack.pop().ok_or(Error::StackUnderflow)?;
+            }
+            FloatOp::Swap => {
+                let second_to_last = self
+                    .float_stack
+                    .len()
+                    .checked_sub(2)
+                    .ok_or(Error::StackUnderflow)?;
+                self.float_stack.swap(second_to_last, second_to_last + 1);
+            }
+            FloatOp::Over => {
+                let second_to_last = self
+                    .float_stack
+                    .len()
+                    .checked_sub(2)
+                    .ok_or(Error::StackUnderflow)?;
+                self.float_stack.push(self.float_stack[second_to_last]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate an output operation against the output sink
+    fn eval_output_op(&mut self, op: OutputOp) -> ForthResult {
+        match op {
+            OutputOp::Print => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                let rendered = format_value(top, self.base);
+                write!(self.output, "{} ", rendered).map_err(|_| Error::Io)?;
+            }
+            OutputOp::PrintStack => {
+                write!(self.output, "<{}>", self.stack.len()).map_err(|_| Error::Io)?;
+                for &value in self.stack.iter() {
+                    let rendered = format_value(value, self.base);
+                    write!(self.output, " {}", rendered).map_err(|_| Error::Io)?;
+                }
+                write!(self.output, " ").map_err(|_| Error::Io)?;
+            }
+            OutputOp::Emit => {
+                let top = self.stack.pop().ok_or(Error::StackUnderflow)?;
+                let character = char::from_u32(top as u32).unwrap_or(char::REPLACEMENT_CHARACTER);
+                write!(self.output, "{}", character).map_err(|_| Error::Io)?;
+            }
+            OutputOp::Cr => {
+                writeln!(self.output).map_err(|_| Error::Io)?;
+            }
         }
         Ok(())
     }
 
-    /// Evaluate list of expressions against a definition environment
-    fn eval_stack(
-        &mut self,
-        exprs: Vec<Expr>,
-        def_env: HashMap<String, Definition>,
-    ) -> ForthResult {
-        for expr in exprs.into_iter() {
-            match expr {
-                Expr::Value(value) => self.stack.push(value),
-                Expr::Symbol(symbol) => {
-                    // Chain lookups from the definition environment to the parent environment
-                    match def_env
-                        .get(&symbol)
-                        .or_else(|| self.env.get(&symbol))
-                        .cloned()
-                    {
-                        Some(Definition { exprs, env }) => {
-                            self.eval_stack(exprs, env)?;
+    /// Default maximum depth of nested word invocations. With no
+    /// conditionals in the language this is what stops `RECURSE` from
+    /// running forever, while staying roomy enough for legitimately deep
+    /// definition chains.
+    const DEFAULT_MAX_CALL_DEPTH: usize = 1024;
+
+    /// Append a definition slot and point `name` at it
+    fn define(&mut self, name: String, exprs: Vec<Expr>) {
+        let slot = self.definitions.len();
+        let code = compile(&exprs, true);
+        self.definitions.push(DictEntry {
+            name: name.clone(),
+            strings_len: self.strings.len(),
+            immediate: false,
+            exprs: Rc::new(exprs),
+            code: Rc::new(code),
+        });
+        self.env.insert(name, slot);
+    }
+
+    /// Truncate the dictionary and string storage, then rebuild the name
+    /// table from the surviving slots so shadowed definitions reappear
+    fn rollback(&mut self, definitions_len: usize, strings_len: usize) {
+        self.definitions.truncate(definitions_len);
+        self.strings.truncate(strings_len);
+        self.env = self
+            .definitions
+            .iter()
+            .enumerate()
+            .map(|(slot, entry)| (entry.name.clone(), slot))
+            .collect();
+    }
+
+    /// Enter a word: push a call frame for `exprs`, enforcing the
+    /// call-depth limit
+    fn push_frame(&self, frames: &mut Vec<Frame>, code: Rc<Vec<Instr>>) -> ForthResult {
+        if frames.len() > self.max_call_depth {
+            return Err(Error::RecursionLimit);
+        }
+        frames.push(Frame { code, pc: 0 });
+        Ok(())
+    }
+
+    /// Evaluate a list of expressions on an explicit frame stack. Nested
+    /// word invocations push frames rather than recursing into the host
+    /// stack, so evaluation depth is bounded only by the configured
+    /// call-depth limit, not by host stack space.
+    fn eval_stack(&mut self, code: Rc<Vec<Instr>>) -> ForthResult {
+        let mut frames = vec![Frame { code, pc: 0 }];
+        while let Some(frame) = frames.last_mut() {
+            if frame.pc == frame.code.len() {
+                frames.pop();
+                continue;
+            }
+            let code = Rc::clone(&frame.code);
+            let instr = &code[frame.pc];
+            frame.pc += 1;
+            match instr {
+                Instr::Push(value) => self.stack.push(*value),
+                Instr::Number(digits) => {
+                    let value =
+                        Value::from_str_radix(digits, self.base).map_err(|_| Error::UnknownWord)?;
+                    self.stack.push(value);
+                }
+                Instr::PushFloat(value) => self.float_stack.push(*value),
+                Instr::Print(text) => {
+                    write!(self.output, "{}", text).map_err(|_| Error::Io)?;
+                }
+                Instr::StringLit(text) => {
+                    let addr = self.strings.len() as Value;
+                    let len = text.chars().count() as Value;
+                    self.strings.push(text.clone());
+                    self.stack.push(addr);
+                    self.stack.push(len);
+                }
+                // Re-invoking the current word means running its whole body
+                // again, one frame deeper.
+                Instr::Recurse => {
+                    if let Some(tracer) = self.tracer.as_mut() {
+                        tracer.on_word("recurse", &self.stack);
+                    }
+                    self.push_frame(&mut frames, Rc::clone(&code))?;
+                }
+                Instr::Call(slot) => {
+                    if let Some(tracer) = self.tracer.as_mut() {
+                        tracer.on_word(&self.definitions[*slot].name, &self.stack);
+                    }
+                    let body = Rc::clone(&self.definitions[*slot].code);
+                    self.push_frame(&mut frames, body)?;
+                }
+                Instr::Builtin(op) => {
+                    if let Some(tracer) = self.tracer.as_mut() {
+                        tracer.on_word(builtin_name(*op), &self.stack);
+                    }
+                    self.eval_builtin_op(*op)?;
+                }
+                #[cfg(feature = "std")]
+                Instr::Include(path) => self.eval_file(path).map_err(|error| error.error)?,
+                Instr::MakeMarker(name) => {
+                    let marker = Expr::Marker {
+                        definitions_len: self.definitions.len(),
+                        strings_len: self.strings.len(),
+                    };
+                    self.define(name.clone(), vec![marker]);
+                }
+                Instr::Forget(name) => {
+                    let &slot = self.env.get(name).ok_or(Error::UnknownWord)?;
+                    let strings_len = self.definitions[slot].strings_len;
+                    self.rollback(slot, strings_len);
+                }
+                Instr::Marker {
+                    definitions_len,
+                    strings_len,
+                } => self.rollback(*definitions_len, *strings_len),
+                Instr::Late(symbol) => {
+                    if let Some(tracer) = self.tracer.as_mut() {
+                        tracer.on_word(symbol, &self.stack);
+                    }
+                    // Late-bound names: whatever the name means right now,
+                    // falling back to the builtins
+                    match self.env.get(symbol).copied() {
+                        Some(slot) => {
+                            let body = Rc::clone(&self.definitions[slot].code);
+                            self.push_frame(&mut frames, body)?;
                         }
-                        // if we didn't find the name in the definition environment or the parent
-                        // and the symbol is builtin operation then execute it
+                        // `IMMEDIATE` marks the most recently defined word
+                        // as executing at compile time
+                        None if symbol == "immediate" => {
+                            let entry = self.definitions.last_mut().ok_or(Error::UnknownWord)?;
+                            entry.immediate = true;
+                        }
+                        // if the symbol isn't in the dictionary and it is a
+                        // builtin operation then execute it
                         None if Self::BUILTIN_OPS.contains(&symbol.as_str()) => {
                             let (_, builtin_op) =
-                                parse_builtin_op(&symbol).map_err(|_| Error::InvalidWord)?;
+                                parse_builtin_op(symbol).map_err(|_| Error::InvalidWord)?;
                             self.eval_builtin_op(builtin_op)?;
                         }
-                        // otherwise we don't know the symbol, so it's an error
-                        _ => return Err(Error::UnknownWord),
+                        // a token that is not a word may still be a number
+                        // in the current base (e.g. `ff` after `HEX`)
+                        None => {
+                            let value = Value::from_str_radix(symbol, self.base)
+                                .map_err(|_| Error::UnknownWord)?;
+                            self.stack.push(value);
+                        }
                     }
                 }
             }
+            let height = self
+                .stack
+                .len()
+                .max(self.return_stack.len())
+                .max(self.float_stack.len());
+            if height > self.max_stack {
+                return Err(Error::StackOverflow);
+            }
         }
         Ok(())
     }
 }
+
+/// Magic bytes identifying a dictionary image
+const IMAGE_MAGIC: &[u8; 8] = b"FORTHIMG";
+/// Image format version, bumped on incompatible layout changes
+const IMAGE_VERSION: u8 = 1;
+
+/// Append a length as a little-endian `u32`
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+/// Append a length-prefixed string
+fn write_str(out: &mut Vec<u8>, string: &str) {
+    write_len(out, string.len());
+    out.extend_from_slice(string.as_bytes());
+}
+
+/// Append a length-prefixed list of cells
+fn write_values(out: &mut Vec<u8>, values: &[Value]) {
+    write_len(out, values.len());
+    for value in values.iter() {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Append a tagged expression
+fn write_expr(out: &mut Vec<u8>, expr: &Expr) {
+    match expr {
+        Expr::Value(value) => {
+            out.push(0);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        Expr::FloatValue(value) => {
+            out.push(1);
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        Expr::Symbol(symbol) => {
+            out.push(2);
+            write_str(out, symbol);
+        }
+        Expr::Print(text) => {
+            out.push(3);
+            write_str(out, text);
+        }
+        Expr::StringLit(text) => {
+            out.push(4);
+            write_str(out, text);
+        }
+        Expr::Recurse => out.push(5),
+        Expr::Call(slot) => {
+            out.push(6);
+            write_len(out, *slot);
+        }
+        Expr::MakeMarker(name) => {
+            out.push(7);
+            write_str(out, name);
+        }
+        Expr::Forget(name) => {
+            out.push(8);
+            write_str(out, name);
+        }
+        Expr::Marker {
+            definitions_len,
+            strings_len,
+        } => {
+            out.push(9);
+            write_len(out, *definitions_len);
+            write_len(out, *strings_len);
+        }
+        #[cfg(feature = "std")]
+        Expr::Include(path) => {
+            out.push(10);
+            write_str(out, path);
+        }
+        Expr::Number(digits) => {
+            out.push(11);
+            write_str(out, digits);
+        }
+    }
+}
+
+/// Cursor over image bytes; every read fails with [`Error::InvalidImage`]
+/// when the image runs out or holds something unexpected
+struct ImageReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ImageReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() {
+            return Err(Error::InvalidImage);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_len(&mut self) -> Result<usize, Error> {
+        let bytes = self.take(4)?.try_into().expect("exactly four bytes");
+        Ok(u32::from_le_bytes(bytes) as usize)
+    }
+
+    fn read_value(&mut self) -> Result<Value, Error> {
+        let bytes = self.take(std::mem::size_of::<Value>())?;
+        Ok(Value::from_le_bytes(bytes.try_into().expect("cell width")))
+    }
+
+    fn read_f64(&mut self) -> Result<f64, Error> {
+        let bytes = self.take(8)?.try_into().expect("exactly eight bytes");
+        Ok(f64::from_le_bytes(bytes))
+    }
+
+    fn read_str(&mut self) -> Result<String, Error> {
+        let len = self.read_len()?;
+        let bytes = self.take(len)?.to_vec();
+        String::from_utf8(bytes).map_err(|_| Error::InvalidImage)
+    }
+
+    fn read_values(&mut self) -> Result<Vec<Value>, Error> {
+        (0..self.read_len()?).map(|_| self.read_value()).collect()
+    }
+
+    fn read_expr(&mut self) -> Result<Expr, Error> {
+        Ok(match self.read_u8()? {
+            0 => Expr::Value(self.read_value()?),
+            1 => Expr::FloatValue(self.read_f64()?),
+            2 => Expr::Symbol(self.read_str()?),
+            3 => Expr::Print(self.read_str()?),
+            4 => Expr::StringLit(self.read_str()?),
+            5 => Expr::Recurse,
+            6 => Expr::Call(self.read_len()?),
+            7 => Expr::MakeMarker(self.read_str()?),
+            8 => Expr::Forget(self.read_str()?),
+            9 => Expr::Marker {
+                definitions_len: self.read_len()?,
+                strings_len: self.read_len()?,
+            },
+            #[cfg(feature = "std")]
+            10 => Expr::Include(self.read_str()?),
+            11 => Expr::Number(self.read_str()?),
+            _ => return Err(Error::InvalidImage),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use forth::{Error, Forth};
+
+#[test]
+fn mod_follows_truncated_division() {
+    let mut f = Forth::new();
+    assert!(f.eval("7 3 MOD -7 3 MOD 7 -3 MOD").is_ok());
+    assert_eq!(f.stack(), [1, -1, 1]);
+}
+
+#[test]
+fn mod_by_zero_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("4 0 MOD"), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn div_mod_pushes_remainder_then_quotient() {
+    let mut f = Forth::new();
+    assert!(f.eval("7 3 /MOD").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn div_mod_with_negative_dividend() {
+    let mut f = Forth::new();
+    assert!(f.eval("-7 3 /MOD").is_ok());
+    assert_eq!(f.stack(), [-1, -2]);
+}
+
+#[test]
+fn div_mod_by_zero_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("7 0 /MOD"), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn negate_flips_the_sign() {
+    let mut f = Forth::new();
+    assert!(f.eval("3 NEGATE -4 NEGATE 0 NEGATE").is_ok());
+    assert_eq!(f.stack(), [-3, 4, 0]);
+}
+
+#[test]
+fn abs_drops_the_sign() {
+    let mut f = Forth::new();
+    assert!(f.eval("-5 ABS 5 ABS").is_ok());
+    assert_eq!(f.stack(), [5, 5]);
+}
+
+#[test]
+fn min_and_max_with_negative_operands() {
+    let mut f = Forth::new();
+    assert!(f.eval("-2 3 MIN -2 3 MAX").is_ok());
+    assert_eq!(f.stack(), [-2, 3]);
+}
+
+#[test]
+fn extended_arithmetic_underflows_like_the_basics() {
+    for program in ["MOD", "1 /MOD", "NEGATE", "ABS", "1 MIN", "MAX"] {
+        let mut f = Forth::new();
+        assert_eq!(
+            f.eval(program),
+            Err(Error::StackUnderflow),
+            "expected underflow evaluating {:?}",
+            program
+        );
+    }
+}
+
+#[test]
+fn extended_words_can_be_redefined() {
+    let mut f = Forth::new();
+    assert!(f.eval(": MAX MIN ;").is_ok());
+    assert!(f.eval("1 5 MAX").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
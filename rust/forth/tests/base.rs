@@ -0,0 +1,73 @@
+use forth::{Error, Forth};
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn hex_changes_how_numbers_are_read() {
+    let mut f = Forth::new();
+    assert!(f.eval("HEX ff 10 DECIMAL 10").is_ok());
+    assert_eq!(f.stack(), [255, 16, 10]);
+}
+
+#[test]
+fn dollar_literals_are_hex_in_any_base() {
+    let mut f = Forth::new();
+    assert!(f.eval("$ff DECIMAL $10 $-a").is_ok());
+    assert_eq!(f.stack(), [255, 16, -10]);
+}
+
+#[test]
+fn printing_respects_the_base() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("255 DUP NEGATE HEX . .").is_ok());
+    assert_eq!(buffer.contents(), "-ff ff ");
+}
+
+#[test]
+fn print_stack_respects_the_base() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("10 16 HEX .S").is_ok());
+    assert_eq!(buffer.contents(), "<2> a 10 ");
+}
+
+#[test]
+fn base_pushes_the_current_base() {
+    let mut f = Forth::new();
+    assert!(f.eval("BASE HEX BASE DECIMAL").is_ok());
+    assert_eq!(f.stack(), [10, 16]);
+}
+
+#[test]
+fn definitions_capture_the_compile_time_base() {
+    let mut f = Forth::new();
+    assert!(f.eval("HEX : mask ff ; DECIMAL mask").is_ok());
+    assert_eq!(f.stack(), [255]);
+}
+
+#[test]
+fn digits_beyond_the_base_are_unknown_words() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("HEX fg"), Err(Error::UnknownWord));
+}
@@ -0,0 +1,48 @@
+use forth::Forth;
+
+#[test]
+fn paren_comments_are_ignored() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 ( this is a comment ) 2 +").is_ok());
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn backslash_comments_run_to_end_of_line() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 \\ everything here is ignored + *\n+").is_ok());
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn comments_work_inside_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": square ( n -- n*n ) dup * ;").is_ok());
+    assert!(f.eval("4 square").is_ok());
+    assert_eq!(f.stack(), [16]);
+}
+
+#[test]
+fn leading_and_trailing_comments() {
+    let mut f = Forth::new();
+    assert!(f.eval("( leading ) 1 2 ( trailing )").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn comments_do_not_need_surrounding_spaces() {
+    let mut f = Forth::new();
+    assert!(f.eval("1( one )2( two )+").is_ok());
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn multiline_programs_with_backslash_comments() {
+    let mut f = Forth::new();
+    let program = "\
+: double \\ multiply by two
+  2 * ;
+5 double";
+    assert!(f.eval(program).is_ok());
+    assert_eq!(f.stack(), [10]);
+}
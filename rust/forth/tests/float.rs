@@ -0,0 +1,76 @@
+use forth::{Error, Forth};
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn float_literals_go_to_the_float_stack() {
+    let mut f = Forth::new();
+    assert!(f.eval("1.5 -2.25 3").is_ok());
+    assert_eq!(f.float_stack(), [1.5, -2.25]);
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn float_arithmetic() {
+    let mut f = Forth::new();
+    assert!(f.eval("1.5 2.5 F+ 10.0 F* 0.5 F- 2.0 F/").is_ok());
+    assert_eq!(f.float_stack(), [19.75]);
+}
+
+#[test]
+fn float_division_by_zero_is_an_infinity() {
+    let mut f = Forth::new();
+    assert!(f.eval("1.0 0.0 F/").is_ok());
+    assert_eq!(f.float_stack(), [f64::INFINITY]);
+}
+
+#[test]
+fn float_stack_shuffling() {
+    let mut f = Forth::new();
+    assert!(f.eval("1.0 2.0 FDUP FSWAP FOVER FDROP").is_ok());
+    assert_eq!(f.float_stack(), [1.0, 2.0, 2.0]);
+}
+
+#[test]
+fn float_print_pops_and_writes() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("2.5 F.").is_ok());
+    assert_eq!(buffer.contents(), "2.5 ");
+    assert!(f.float_stack().is_empty());
+}
+
+#[test]
+fn float_words_underflow_like_integer_ones() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("1.0 F+"), Err(Error::StackUnderflow));
+    assert_eq!(f.eval("FDUP"), Err(Error::StackUnderflow));
+}
+
+#[test]
+fn float_words_work_inside_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": average F+ 2.0 F/ ;").is_ok());
+    assert!(f.eval("1.0 2.0 average").is_ok());
+    assert_eq!(f.float_stack(), [1.5]);
+}
@@ -0,0 +1,95 @@
+use forth::{Error, Forth};
+
+#[test]
+fn definitions_survive_a_save_and_load() {
+    let mut original = Forth::new();
+    assert!(original
+        .eval(": double 2 * ; : quad double double ;")
+        .is_ok());
+    let image = original.save_image();
+
+    let mut restored = Forth::new();
+    assert!(restored.load_image(&image).is_ok());
+    assert!(restored.eval("3 quad").is_ok());
+    assert_eq!(restored.stack(), [12]);
+}
+
+#[test]
+fn stacks_and_strings_are_part_of_the_image() {
+    let mut original = Forth::new();
+    assert!(original.eval("1 2 S\" hello\" 3.5").is_ok());
+    let image = original.save_image();
+
+    let mut restored = Forth::new();
+    assert!(restored.load_image(&image).is_ok());
+    assert_eq!(restored.stack(), original.stack());
+    assert_eq!(restored.float_stack(), [3.5]);
+    let addr = restored.stack()[2];
+    assert_eq!(restored.string(addr), Some("hello"));
+}
+
+#[test]
+fn loading_replaces_existing_definitions() {
+    let mut original = Forth::new();
+    assert!(original.eval(": word 1 ;").is_ok());
+    let image = original.save_image();
+
+    let mut other = Forth::new();
+    assert!(other.eval(": word 2 ; : extra 3 ;").is_ok());
+    assert!(other.load_image(&image).is_ok());
+    assert!(other.eval("word").is_ok());
+    assert_eq!(other.stack(), [1]);
+    assert_eq!(other.eval("extra"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn immediate_flags_are_preserved() {
+    let mut original = Forth::new();
+    assert!(original.eval(": hi 1 ; IMMEDIATE").is_ok());
+    let image = original.save_image();
+
+    let mut restored = Forth::new();
+    assert!(restored.load_image(&image).is_ok());
+    assert!(restored.eval(": test hi 2 ;").is_ok());
+    assert_eq!(restored.stack(), [1]);
+}
+
+#[test]
+fn garbage_is_rejected() {
+    let mut f = Forth::new();
+    assert_eq!(f.load_image(b"not an image"), Err(Error::InvalidImage));
+}
+
+#[test]
+fn truncated_images_are_rejected() {
+    let mut original = Forth::new();
+    assert!(original.eval(": word 1 ;").is_ok());
+    let image = original.save_image();
+
+    let mut restored = Forth::new();
+    assert_eq!(
+        restored.load_image(&image[..image.len() - 1]),
+        Err(Error::InvalidImage)
+    );
+}
+
+#[test]
+fn a_failed_load_leaves_the_interpreter_untouched() {
+    let mut f = Forth::new();
+    assert!(f.eval(": word 1 ; 9").is_ok());
+    assert_eq!(f.load_image(b"FORTHIMG junk"), Err(Error::InvalidImage));
+    assert!(f.eval("word").is_ok());
+    assert_eq!(f.stack(), [9, 1]);
+}
+
+#[test]
+fn data_space_is_part_of_the_image() {
+    let mut original = Forth::new();
+    assert!(original.eval("2 ALLOT 42 0 !").is_ok());
+    let image = original.save_image();
+
+    let mut restored = Forth::new();
+    assert!(restored.load_image(&image).is_ok());
+    assert!(restored.eval("0 @").is_ok());
+    assert_eq!(restored.stack(), [42]);
+}
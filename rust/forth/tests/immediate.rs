@@ -0,0 +1,57 @@
+use forth::{Error, Forth};
+
+#[test]
+fn bracketed_expressions_run_at_compile_time() {
+    let mut f = Forth::new();
+    assert!(f.eval(": six [ 2 3 * ] LITERAL ;").is_ok());
+    assert!(f.stack().is_empty());
+    assert!(f.eval("six").is_ok());
+    assert_eq!(f.stack(), [6]);
+}
+
+#[test]
+fn literal_compiles_the_top_of_the_stack() {
+    let mut f = Forth::new();
+    assert!(f.eval("42 : answer LITERAL ;").is_ok());
+    assert!(f.stack().is_empty());
+    assert!(f.eval("answer answer").is_ok());
+    assert_eq!(f.stack(), [42, 42]);
+}
+
+#[test]
+fn literal_with_an_empty_stack_is_an_underflow() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(": broken LITERAL ;"), Err(Error::StackUnderflow));
+}
+
+#[test]
+fn immediate_words_run_during_compilation() {
+    let mut f = Forth::new();
+    assert!(f.eval(": hi 1 ; IMMEDIATE").is_ok());
+    assert!(f.eval(": test hi 2 ;").is_ok());
+    assert_eq!(f.stack(), [1]);
+    assert!(f.eval("test").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn ordinary_words_are_compiled_not_run() {
+    let mut f = Forth::new();
+    assert!(f.eval(": hi 1 ;").is_ok());
+    assert!(f.eval(": test hi 2 ;").is_ok());
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn immediate_with_an_empty_dictionary_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("IMMEDIATE"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn compile_state_resumes_after_a_bracket_pair() {
+    let mut f = Forth::new();
+    assert!(f.eval(": word [ 1 ] LITERAL 2 ;").is_ok());
+    assert!(f.eval("word").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
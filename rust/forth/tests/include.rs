@@ -0,0 +1,98 @@
+#![cfg(feature = "std")]
+
+use forth::{Error, FileError, Forth};
+use std::fs;
+use std::path::PathBuf;
+
+fn source_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn eval_file_runs_the_whole_file() {
+    let path = source_file("include-basic.fs", ": double 2 * ;\n5 double\n");
+    let mut f = Forth::new();
+    assert!(f.eval_file(&path).is_ok());
+    assert_eq!(f.stack(), [10]);
+}
+
+#[test]
+fn include_loads_another_file() {
+    let library = source_file("include-library.fs", ": triple 3 * ;\n");
+    let main = source_file(
+        "include-main.fs",
+        &format!("INCLUDE \"{}\"\n7 triple\n", library.display()),
+    );
+    let mut f = Forth::new();
+    assert!(f.eval_file(&main).is_ok());
+    assert_eq!(f.stack(), [21]);
+}
+
+#[test]
+fn include_works_from_eval_too() {
+    let library = source_file("include-eval.fs", "1 2 3\n");
+    let mut f = Forth::new();
+    assert!(f
+        .eval(&format!("INCLUDE \"{}\"", library.display()))
+        .is_ok());
+    assert_eq!(f.stack(), [1, 2, 3]);
+}
+
+#[test]
+fn errors_carry_the_file_and_line() {
+    let path = source_file("include-line.fs", ": ok 1 ;\nok\nunknown-word\n");
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval_file(&path),
+        Err(FileError {
+            path: path.clone(),
+            line: 3,
+            error: Error::UnknownWord,
+        })
+    );
+}
+
+#[test]
+fn missing_files_fail_with_io() {
+    let path = std::env::temp_dir().join("include-does-not-exist.fs");
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval_file(&path),
+        Err(FileError {
+            path,
+            line: 0,
+            error: Error::Io,
+        })
+    );
+}
+
+#[test]
+fn include_cycles_are_rejected() {
+    let first = std::env::temp_dir().join("include-cycle-a.fs");
+    let second = std::env::temp_dir().join("include-cycle-b.fs");
+    fs::write(&first, format!("INCLUDE \"{}\"\n", second.display())).unwrap();
+    fs::write(&second, format!("INCLUDE \"{}\"\n", first.display())).unwrap();
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval_file(&first),
+        Err(FileError {
+            path: first,
+            line: 1,
+            error: Error::IncludeCycle,
+        })
+    );
+}
+
+#[test]
+fn a_file_may_be_included_twice_sequentially() {
+    let library = source_file("include-twice.fs", "1\n");
+    let main = source_file(
+        "include-twice-main.fs",
+        &format!("INCLUDE \"{0}\" INCLUDE \"{0}\"\n", library.display()),
+    );
+    let mut f = Forth::new();
+    assert!(f.eval_file(&main).is_ok());
+    assert_eq!(f.stack(), [1, 1]);
+}
@@ -0,0 +1,43 @@
+use forth::{Error, Forth};
+
+#[test]
+fn stack_growth_past_the_limit_is_an_error() {
+    let mut f = Forth::with_limits(4, 1024);
+    assert_eq!(f.eval("1 2 3 4 5"), Err(Error::StackOverflow));
+}
+
+#[test]
+fn the_stack_may_fill_up_to_the_limit() {
+    let mut f = Forth::with_limits(4, 1024);
+    assert!(f.eval("1 2 3 4").is_ok());
+    assert_eq!(f.stack(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn the_return_stack_counts_against_the_limit() {
+    let mut f = Forth::with_limits(2, 1024);
+    assert_eq!(f.eval("1 >R 2 >R 3 >R"), Err(Error::StackOverflow));
+}
+
+#[test]
+fn runaway_recursion_stops_at_the_call_depth_limit() {
+    let mut f = Forth::with_limits(usize::MAX, 8);
+    assert!(f.eval(": spin RECURSE ;").is_ok());
+    assert_eq!(f.eval("spin"), Err(Error::RecursionLimit));
+}
+
+#[test]
+fn calls_within_the_depth_limit_succeed() {
+    let mut f = Forth::with_limits(usize::MAX, 8);
+    assert!(f.eval(": one 1 ; : two one one ; : four two two ;").is_ok());
+    assert!(f.eval("four").is_ok());
+    assert_eq!(f.stack(), [1, 1, 1, 1]);
+}
+
+#[test]
+fn default_interpreter_keeps_the_roomy_limits() {
+    let mut f = Forth::new();
+    let program = (0..2000).map(|_| "1").collect::<Vec<_>>().join(" ");
+    assert!(f.eval(&program).is_ok());
+    assert_eq!(f.stack().len(), 2000);
+}
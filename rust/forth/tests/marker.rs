@@ -0,0 +1,66 @@
+use forth::{Error, Forth};
+
+#[test]
+fn executing_a_marker_forgets_later_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval("MARKER checkpoint").is_ok());
+    assert!(f.eval(": later 1 ;").is_ok());
+    assert!(f.eval("checkpoint").is_ok());
+    assert_eq!(f.eval("later"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn markers_remove_themselves() {
+    let mut f = Forth::new();
+    assert!(f.eval("MARKER checkpoint checkpoint").is_ok());
+    assert_eq!(f.eval("checkpoint"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn rollback_restores_shadowed_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": word 1 ; MARKER checkpoint : word 2 ;").is_ok());
+    assert!(f.eval("word checkpoint word").is_ok());
+    assert_eq!(f.stack(), [2, 1]);
+}
+
+#[test]
+fn markers_roll_back_string_storage() {
+    let mut f = Forth::new();
+    assert!(f.eval("MARKER checkpoint S\" transient\"").is_ok());
+    let addr = f.stack()[0];
+    assert_eq!(f.string(addr), Some("transient"));
+    assert!(f.eval("checkpoint").is_ok());
+    assert_eq!(f.string(addr), None);
+}
+
+#[test]
+fn forget_drops_the_word_and_everything_after() {
+    let mut f = Forth::new();
+    assert!(f.eval(": first 1 ; : second 2 ; : third 3 ;").is_ok());
+    assert!(f.eval("FORGET second").is_ok());
+    assert!(f.eval("first").is_ok());
+    assert_eq!(f.eval("second"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("third"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn forget_restores_the_previous_definition() {
+    let mut f = Forth::new();
+    assert!(f.eval(": word 1 ; : word 2 ;").is_ok());
+    assert!(f.eval("FORGET word word").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn forgetting_an_unknown_word_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("FORGET missing"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn stack_contents_survive_rollback() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 MARKER checkpoint 3 checkpoint").is_ok());
+    assert_eq!(f.stack(), [1, 2, 3]);
+}
@@ -0,0 +1,63 @@
+use forth::{Error, Forth};
+
+#[test]
+fn allotted_cells_can_be_stored_and_fetched() {
+    let mut f = Forth::new();
+    assert!(f.eval("HERE 1 CELLS ALLOT").is_ok());
+    assert!(f.eval("99 OVER !").is_ok());
+    assert!(f.eval("@").is_ok());
+    assert_eq!(f.stack(), [99]);
+}
+
+#[test]
+fn cells_is_the_identity_in_cell_addressed_memory() {
+    let mut f = Forth::new();
+    assert!(f.eval("3 CELLS").is_ok());
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn allot_zero_fills_new_cells() {
+    let mut f = Forth::new();
+    assert!(f.eval("4 ALLOT 2 @").is_ok());
+    assert_eq!(f.stack(), [0]);
+}
+
+#[test]
+fn plus_store_adds_in_place() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 ALLOT 10 0 ! 5 0 +! 0 @").is_ok());
+    assert_eq!(f.stack(), [15]);
+}
+
+#[test]
+fn arrays_work_inside_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval("3 CELLS ALLOT").is_ok());
+    assert!(f.eval(": fill-slot CELLS DUP >R ! R> ;").is_ok());
+    assert!(f.eval("7 0 fill-slot DROP 8 1 fill-slot DROP").is_ok());
+    assert!(f.eval("0 @ 1 @").is_ok());
+    assert_eq!(f.stack(), [7, 8]);
+}
+
+#[test]
+fn out_of_bounds_fetch_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("0 @"), Err(Error::InvalidAddress));
+}
+
+#[test]
+fn out_of_bounds_store_is_an_error() {
+    let mut f = Forth::new();
+    assert!(f.eval("2 ALLOT").is_ok());
+    assert_eq!(f.eval("1 2 !"), Err(Error::InvalidAddress));
+    assert_eq!(f.eval("1 -1 !"), Err(Error::InvalidAddress));
+}
+
+#[test]
+fn negative_allot_releases_cells() {
+    let mut f = Forth::new();
+    assert!(f.eval("4 ALLOT -2 ALLOT").is_ok());
+    assert_eq!(f.eval("2 @"), Err(Error::InvalidAddress));
+    assert_eq!(f.eval("-3 ALLOT"), Err(Error::InvalidAddress));
+}
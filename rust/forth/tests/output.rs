@@ -0,0 +1,68 @@
+use forth::{Error, Forth};
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+/// An output sink that the test keeps a handle to after handing it to the
+/// interpreter.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn dot_pops_and_prints() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("1 2 3 .").is_ok());
+    assert_eq!(buffer.contents(), "3 ");
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn dot_s_prints_without_consuming() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("1 2 3 .S").is_ok());
+    assert_eq!(buffer.contents(), "<3> 1 2 3 ");
+    assert_eq!(f.stack(), [1, 2, 3]);
+}
+
+#[test]
+fn emit_and_cr_print_characters() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("72 EMIT 105 EMIT CR").is_ok());
+    assert_eq!(buffer.contents(), "Hi\n");
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn output_words_work_inside_definitions() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval(": print-twice dup . .;").is_ok());
+    assert!(f.eval("42 print-twice").is_ok());
+    assert_eq!(buffer.contents(), "42 42 ");
+}
+
+#[test]
+fn dot_underflows_on_an_empty_stack() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert_eq!(f.eval("."), Err(Error::StackUnderflow));
+}
@@ -0,0 +1,57 @@
+use forth::{Error, Forth, Value};
+
+// Written against `forth::Value` so they hold for any configured cell
+// width (default i32, or the cell64 / cell128 features).
+
+#[test]
+fn addition_overflow_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(&format!("{} 1 +", Value::MAX)), Err(Error::Overflow));
+}
+
+#[test]
+fn subtraction_overflow_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(&format!("{} 1 -", Value::MIN)), Err(Error::Overflow));
+}
+
+#[test]
+fn multiplication_overflow_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(&format!("{} 2 *", Value::MAX)), Err(Error::Overflow));
+}
+
+#[test]
+fn dividing_min_by_minus_one_overflows() {
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval(&format!("{} -1 /", Value::MIN)),
+        Err(Error::Overflow)
+    );
+}
+
+#[test]
+fn division_by_zero_is_still_its_own_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("1 0 /"), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn negate_and_abs_overflow_on_the_minimum_cell() {
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval(&format!("{} NEGATE", Value::MIN)),
+        Err(Error::Overflow)
+    );
+    let mut f = Forth::new();
+    assert_eq!(f.eval(&format!("{} ABS", Value::MIN)), Err(Error::Overflow));
+}
+
+#[test]
+fn in_range_arithmetic_is_unchanged() {
+    let mut f = Forth::new();
+    assert!(f
+        .eval(&format!("{} 1 - 1 + {} 1 + 1 -", Value::MAX, Value::MIN))
+        .is_ok());
+    assert_eq!(f.stack(), [Value::MAX, Value::MIN]);
+}
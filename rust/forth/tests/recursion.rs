@@ -0,0 +1,66 @@
+use forth::{Error, Forth};
+
+#[test]
+fn recurse_reinvokes_the_word_being_defined() {
+    let mut f = Forth::new();
+    assert!(f.eval(": count-up 1 + RECURSE ;").is_ok());
+    assert_eq!(f.eval("0 count-up"), Err(Error::RecursionLimit));
+    // Each level of recursion ran the increment before hitting the limit.
+    assert!(f.stack()[0] > 1);
+}
+
+#[test]
+fn fresh_words_may_reference_themselves_directly() {
+    let mut f = Forth::new();
+    assert!(f.eval(": spin spin ;").is_ok());
+    assert_eq!(f.eval("spin"), Err(Error::RecursionLimit));
+}
+
+#[test]
+fn redefinition_still_sees_the_previous_definition() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 10 ;").is_ok());
+    assert!(f.eval(": foo foo 1 + ;").is_ok());
+    assert!(f.eval("foo").is_ok());
+    assert_eq!(f.stack(), [11]);
+}
+
+#[test]
+fn recurse_beats_a_previous_definition() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 10 ;").is_ok());
+    assert!(f.eval(": foo foo RECURSE ;").is_ok());
+    assert_eq!(f.eval("foo"), Err(Error::RecursionLimit));
+    // The bare `foo` kept resolving to the old definition at every level.
+    assert!(f.stack().iter().all(|&value| value == 10));
+    assert!(f.stack().len() > 1);
+}
+
+#[test]
+fn recurse_outside_a_definition_is_unknown() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("RECURSE"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn nested_but_finite_calls_still_work() {
+    let mut f = Forth::new();
+    assert!(f.eval(": w0 1 ;").is_ok());
+    for i in 1..=10 {
+        assert!(f.eval(&format!(": w{} w{} ;", i, i - 1)).is_ok());
+    }
+    assert!(f.eval("w10").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn deeply_nested_words_do_not_overflow_the_host_stack() {
+    let mut f = Forth::with_limits(usize::MAX, usize::MAX);
+    assert!(f.eval(": w0 1 ;").is_ok());
+    for level in 1..50_000 {
+        let definition = format!(": w{} w{} ;", level, level - 1);
+        assert!(f.eval(&definition).is_ok());
+    }
+    assert!(f.eval("w49999").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
@@ -0,0 +1,50 @@
+use forth::Forth;
+use std::time::{Duration, Instant};
+
+// The dictionary stores immutable slots referenced by index, so
+// redefinition is O(1) instead of cloning an ever-growing environment.
+// These would take effectively forever under the old cloning scheme.
+
+#[test]
+fn thousands_of_redefinitions_are_cheap() {
+    let start = Instant::now();
+    let mut f = Forth::new();
+    assert!(f.eval(": word 1 ;").is_ok());
+    for _ in 0..1_000 {
+        assert!(f.eval(": word word 1 + ;").is_ok());
+    }
+    assert!(f.eval("word").is_ok());
+    assert_eq!(f.stack(), [1_001]);
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "pathological redefinition took {:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn deep_definition_chains_are_cheap_to_define() {
+    let start = Instant::now();
+    let mut f = Forth::new();
+    assert!(f.eval(": w0 1 ;").is_ok());
+    for i in 1..=50 {
+        assert!(f.eval(&format!(": w{} w{} ;", i, i - 1)).is_ok());
+    }
+    assert!(f.eval("w50").is_ok());
+    assert_eq!(f.stack(), [1]);
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "chained definitions took {:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn redefining_a_dependency_does_not_change_existing_words() {
+    let mut f = Forth::new();
+    assert!(f.eval(": five 5 ;").is_ok());
+    assert!(f.eval(": ten five five + ;").is_ok());
+    assert!(f.eval(": five 6 ;").is_ok());
+    assert!(f.eval("ten five").is_ok());
+    assert_eq!(f.stack(), [10, 6]);
+}
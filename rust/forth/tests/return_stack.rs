@@ -0,0 +1,48 @@
+use forth::{Error, Forth};
+
+#[test]
+fn to_r_moves_values_off_the_data_stack() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 >R").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn r_from_moves_values_back() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 >R 3 R>").is_ok());
+    assert_eq!(f.stack(), [1, 3, 2]);
+}
+
+#[test]
+fn r_fetch_copies_without_consuming() {
+    let mut f = Forth::new();
+    assert!(f.eval("5 >R R@ R@ R>").is_ok());
+    assert_eq!(f.stack(), [5, 5, 5]);
+}
+
+#[test]
+fn return_stack_words_work_inside_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": under >R dup R> ;").is_ok());
+    assert!(f.eval("1 2 under").is_ok());
+    assert_eq!(f.stack(), [1, 1, 2]);
+}
+
+#[test]
+fn to_r_underflows_on_an_empty_data_stack() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(">R"), Err(Error::StackUnderflow));
+}
+
+#[test]
+fn r_from_underflows_on_an_empty_return_stack() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("1 R>"), Err(Error::StackUnderflow));
+}
+
+#[test]
+fn r_fetch_underflows_on_an_empty_return_stack() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("R@"), Err(Error::StackUnderflow));
+}
@@ -0,0 +1,79 @@
+use forth::{Error, Forth};
+
+#[test]
+fn rot_rotates_the_third_value_to_the_top() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 3 ROT").is_ok());
+    assert_eq!(f.stack(), [2, 3, 1]);
+}
+
+#[test]
+fn nip_removes_the_second_value() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 3 NIP").is_ok());
+    assert_eq!(f.stack(), [1, 3]);
+}
+
+#[test]
+fn tuck_copies_the_top_below_the_second() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 TUCK").is_ok());
+    assert_eq!(f.stack(), [2, 1, 2]);
+}
+
+#[test]
+fn two_dup_copies_the_top_pair() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 2DUP").is_ok());
+    assert_eq!(f.stack(), [1, 2, 1, 2]);
+}
+
+#[test]
+fn two_drop_removes_the_top_pair() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 3 4 2DROP").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn two_swap_exchanges_the_top_two_pairs() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 3 4 2SWAP").is_ok());
+    assert_eq!(f.stack(), [3, 4, 1, 2]);
+}
+
+#[test]
+fn shuffling_words_underflow_like_the_basics() {
+    for (program, required) in [
+        ("1 2 ROT", 3),
+        ("NIP", 2),
+        ("1 TUCK", 2),
+        ("1 2DUP", 2),
+        ("1 2DROP", 2),
+        ("1 2 3 2SWAP", 4),
+    ] {
+        let mut f = Forth::new();
+        assert_eq!(
+            f.eval(program),
+            Err(Error::StackUnderflow),
+            "{:?} needs {} values",
+            program,
+            required
+        );
+    }
+}
+
+#[test]
+fn numbers_are_still_numbers() {
+    let mut f = Forth::new();
+    assert!(f.eval("2 -2").is_ok());
+    assert_eq!(f.stack(), [2, -2]);
+}
+
+#[test]
+fn shuffling_words_can_be_redefined() {
+    let mut f = Forth::new();
+    assert!(f.eval(": ROT 2DROP ;").is_ok());
+    assert!(f.eval("1 2 3 ROT").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
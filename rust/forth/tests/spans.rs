@@ -0,0 +1,49 @@
+use forth::{Error, Forth, ParseError};
+
+#[test]
+fn check_accepts_valid_programs() {
+    assert_eq!(Forth::check("1 2 + : double 2 * ; double"), Ok(()));
+}
+
+#[test]
+fn check_reports_the_offending_token_and_offset() {
+    assert_eq!(
+        Forth::check("1 2 &"),
+        Err(ParseError {
+            offset: 4,
+            token: "&".to_string(),
+        })
+    );
+}
+
+#[test]
+fn check_reports_failures_at_the_start() {
+    assert_eq!(
+        Forth::check("&"),
+        Err(ParseError {
+            offset: 0,
+            token: "&".to_string(),
+        })
+    );
+}
+
+#[test]
+fn malformed_definitions_point_at_the_colon() {
+    let error = Forth::check(": 5 2 ;").unwrap_err();
+    assert_eq!(error.offset, 0);
+    assert_eq!(error.token, ":");
+}
+
+#[test]
+fn parse_errors_convert_to_the_coarse_enum() {
+    let error = Forth::check("&").unwrap_err();
+    assert_eq!(Error::from(error), Error::InvalidWord);
+}
+
+#[test]
+fn eval_rejects_trailing_garbage() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("1 2 &"), Err(Error::InvalidWord));
+    // Nothing was evaluated: parsing is all-or-nothing.
+    assert!(f.stack().is_empty());
+}
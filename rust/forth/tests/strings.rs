@@ -0,0 +1,75 @@
+use forth::Forth;
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn dot_quote_prints_text_with_spaces() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval(".\" hello, forth world\"").is_ok());
+    assert_eq!(buffer.contents(), "hello, forth world");
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn dot_quote_works_inside_definitions() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval(": greet .\" hello\" ;").is_ok());
+    assert!(f.eval("greet greet").is_ok());
+    assert_eq!(buffer.contents(), "hellohello");
+}
+
+#[test]
+fn s_quote_pushes_address_and_length() {
+    let mut f = Forth::new();
+    assert!(f.eval("S\" stored text\"").is_ok());
+    let &[addr, len] = f.stack() else {
+        panic!("expected exactly an address and a length")
+    };
+    assert_eq!(len, 11);
+    assert_eq!(f.string(addr), Some("stored text"));
+}
+
+#[test]
+fn each_s_quote_gets_its_own_slot() {
+    let mut f = Forth::new();
+    assert!(f.eval("S\" first\" S\" second\"").is_ok());
+    assert_eq!(f.stack().len(), 4);
+    assert_eq!(f.string(f.stack()[0]), Some("first"));
+    assert_eq!(f.string(f.stack()[2]), Some("second"));
+}
+
+#[test]
+fn strings_mix_with_ordinary_words() {
+    let buffer = SharedBuffer::default();
+    let mut f = Forth::with_output(buffer.clone());
+    assert!(f.eval("1 2 + .\" sum: \" .").is_ok());
+    assert_eq!(buffer.contents(), "sum: 3 ");
+}
+
+#[test]
+fn unterminated_strings_do_not_evaluate() {
+    let mut f = Forth::new();
+    assert!(f.eval(".\" no closing quote").is_err());
+}
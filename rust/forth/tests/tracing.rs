@@ -0,0 +1,78 @@
+use forth::{Forth, Tracer, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Event = (String, Vec<Value>);
+
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<Vec<Event>>>);
+
+impl Recorder {
+    fn events(&self) -> Vec<Event> {
+        self.0.borrow().clone()
+    }
+}
+
+impl Tracer for Recorder {
+    fn on_word(&mut self, word: &str, stack: &[Value]) {
+        self.0.borrow_mut().push((word.to_string(), stack.to_vec()));
+    }
+}
+
+#[test]
+fn builtins_are_traced_with_the_stack_they_see() {
+    let recorder = Recorder::default();
+    let mut f = Forth::new();
+    f.set_tracer(recorder.clone());
+    assert!(f.eval("1 2 + DUP").is_ok());
+    assert_eq!(
+        recorder.events(),
+        [("+".to_string(), vec![1, 2]), ("dup".to_string(), vec![3]),]
+    );
+}
+
+#[test]
+fn user_words_are_traced_by_name() {
+    let recorder = Recorder::default();
+    let mut f = Forth::new();
+    assert!(f.eval(": double 2 * ;").is_ok());
+    f.set_tracer(recorder.clone());
+    assert!(f.eval("5 double").is_ok());
+    assert_eq!(
+        recorder.events(),
+        [
+            ("double".to_string(), vec![5]),
+            ("*".to_string(), vec![5, 2]),
+        ]
+    );
+}
+
+#[test]
+fn nested_calls_are_all_traced() {
+    let recorder = Recorder::default();
+    let mut f = Forth::new();
+    assert!(f.eval(": inner 1 ; : outer inner inner ;").is_ok());
+    f.set_tracer(recorder.clone());
+    assert!(f.eval("outer").is_ok());
+    let words: Vec<_> = recorder.events().into_iter().map(|(w, _)| w).collect();
+    assert_eq!(words, ["outer", "inner", "inner"]);
+}
+
+#[test]
+fn literals_are_not_traced() {
+    let recorder = Recorder::default();
+    let mut f = Forth::new();
+    f.set_tracer(recorder.clone());
+    assert!(f.eval("1 2 3").is_ok());
+    assert!(recorder.events().is_empty());
+}
+
+#[test]
+fn clearing_the_tracer_stops_events() {
+    let recorder = Recorder::default();
+    let mut f = Forth::new();
+    f.set_tracer(recorder.clone());
+    f.clear_tracer();
+    assert!(f.eval("1 2 +").is_ok());
+    assert!(recorder.events().is_empty());
+}
@@ -1,118 +1,119 @@
-#![feature(map_into_keys_values)]
-// The code below is a stub. Just enough to satisfy the compiler.
-// In order to pass the tests you can add-to or change any of this code.
-
-use enumset::EnumSet;
-use std::{collections::BTreeMap, fmt};
+use std::{collections::BTreeMap, io::BufRead, ops::Range};
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
     InvalidRowCount(usize),
     InvalidColumnCount(usize),
+    /// The pixel slice passed to [`convert_bitmap`] doesn't hold
+    /// `width * height` entries; carries the actual length.
+    #[cfg(feature = "bitmap")]
+    InvalidPixelCount(usize),
+    Io,
 }
 
-#[derive(Debug, enumset::EnumSetType)]
-enum Value {
-    Zero,
-    One,
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-}
-
-impl fmt::Display for Value {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Value::Zero => "0",
-                Value::One => "1",
-                Value::Two => "2",
-                Value::Three => "3",
-                Value::Four => "4",
-                Value::Five => "5",
-                Value::Six => "6",
-                Value::Seven => "7",
-                Value::Eight => "8",
-                Value::Nine => "9",
-            }
-        )
-    }
-}
-
-fn parse(input: &str) -> String {
-    let mut valid = EnumSet::<Value>::all();
-    for (line_index, line) in input.split('\n').enumerate() {
-        valid &= match line_index {
-            0 => parse_line_one(&line),
-            1 => parse_line_two(&line),
-            2 => parse_line_three(&line),
-            3 => {
-                // the last line must be 3 spaces
-                if line != "   " {
-                    EnumSet::empty()
-                } else {
-                    continue;
-                }
-            }
-            index => {
-                panic!("more than 4 lines: {}", index + 1);
-            }
-        };
-    }
+/// One 3x4 cell of the input, along with whatever we could make of it.
+#[derive(Debug, PartialEq)]
+pub struct Cell {
+    /// The recognized digit, or `None` when the glyph matched nothing.
+    pub digit: Option<u8>,
+    /// The cell's text exactly as it appeared in the input, rows joined
+    /// with newlines, so callers can show the offending glyph.
+    pub grid: String,
+    /// (row group, column) coordinates of the cell.
+    pub position: (usize, usize),
+}
 
-    valid
-        .into_iter()
-        .next()
-        .map_or_else(|| "?".into(), |value| value.to_string())
+#[derive(Debug, PartialEq)]
+pub struct Ocr {
+    pub rows: Vec<Vec<Cell>>,
 }
 
-fn parse_line_one(line: &str) -> EnumSet<Value> {
-    match line {
-        "   " => Value::One | Value::Four,
-        " _ " => EnumSet::all() - (Value::One | Value::Four),
-        _ => EnumSet::empty(),
-    }
+/// Canonical 3x4 glyphs for 0-9, indexed by digit.
+const DIGIT_GLYPHS: [&str; 10] = [
+    " _ \n| |\n|_|\n   ",
+    "   \n  |\n  |\n   ",
+    " _ \n _|\n|_ \n   ",
+    " _ \n _|\n _|\n   ",
+    "   \n|_|\n  |\n   ",
+    " _ \n|_ \n _|\n   ",
+    " _ \n|_ \n|_|\n   ",
+    " _ \n  |\n  |\n   ",
+    " _ \n|_|\n|_|\n   ",
+    " _ \n|_|\n _|\n   ",
+];
+
+/// Segment-style letters with unambiguous 3x4 forms: the hex digits A-F
+/// plus the letters a seven-segment display can render distinctly.
+const LETTER_GLYPHS: [(char, &str); 11] = [
+    ('a', " _ \n|_|\n| |\n   "),
+    ('b', "   \n|_ \n|_|\n   "),
+    ('c', " _ \n|  \n|_ \n   "),
+    ('d', "   \n _|\n|_|\n   "),
+    ('e', " _ \n|_ \n|_ \n   "),
+    ('f', " _ \n|_ \n|  \n   "),
+    ('h', "   \n|_|\n| |\n   "),
+    ('j', "   \n  |\n|_|\n   "),
+    ('l', "   \n|  \n|_ \n   "),
+    ('p', " _ \n|_|\n|  \n   "),
+    ('u', "   \n| |\n|_|\n   "),
+];
+
+/// The number of cell positions a glyph can differ in (newlines never do).
+const SEGMENTS: usize = 12;
+
+/// A near-miss reading of one cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub digit: u8,
+    /// 1.0 is an exact match; every differing position costs 1/12.
+    pub confidence: f64,
 }
 
-fn parse_line_two(line: &str) -> EnumSet<Value> {
-    match line {
-        "| |" => EnumSet::only(Value::Zero),
-        "  |" => Value::One | Value::Seven,
-        " _|" => Value::Two | Value::Three,
-        "|_|" => Value::Four | Value::Eight | Value::Nine,
-        "|_ " => Value::Five | Value::Six,
-        _ => EnumSet::empty(),
-    }
+fn hamming(a: &str, b: &str) -> usize {
+    a.chars().zip(b.chars()).filter(|(x, y)| x != y).count() + a.len().abs_diff(b.len())
 }
 
-fn parse_line_three(s: &str) -> EnumSet<Value> {
-    match s {
-        "|_|" => Value::Zero | Value::Six | Value::Eight,
-        "  |" => Value::One | Value::Four | Value::Seven,
-        "|_ " => EnumSet::only(Value::Two),
-        " _|" => Value::Three | Value::Five | Value::Nine,
-        _ => EnumSet::empty(),
+impl Cell {
+    /// All ten digits ranked by how close their glyph is to this cell,
+    /// best first; ties break toward the smaller digit.
+    pub fn candidates(&self) -> Vec<Candidate> {
+        let mut ranked = DIGIT_GLYPHS
+            .iter()
+            .enumerate()
+            .map(|(digit, glyph)| Candidate {
+                digit: digit as u8,
+                confidence: 1.0 - hamming(&self.grid, glyph) as f64 / SEGMENTS as f64,
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by(|a, b| {
+            b.confidence
+                .total_cmp(&a.confidence)
+                .then(a.digit.cmp(&b.digit))
+        });
+        ranked
     }
 }
 
-fn parse_numbers_from_line_group(line_group: &str) -> Result<Vec<String>, Error> {
+fn recognize(input: &str) -> Option<u8> {
+    DIGIT_GLYPHS
+        .iter()
+        .position(|&glyph| glyph == input)
+        .map(|digit| digit as u8)
+}
+
+/// Split one group of lines into per-cell grids, `cell_width` columns at
+/// a time.
+fn parse_cells(line_group: &str, cell_width: usize) -> Result<Vec<String>, Error> {
     let mut map = BTreeMap::new();
 
     for line in line_group.split('\n') {
         let nchars = line.len();
 
-        if nchars % 3 != 0 {
+        if !nchars.is_multiple_of(cell_width) {
             return Err(Error::InvalidColumnCount(nchars));
         }
 
-        for (i, chunk) in line.as_bytes().chunks(3).enumerate() {
+        for (i, chunk) in line.as_bytes().chunks(cell_width).enumerate() {
             let bytes = map.entry(i).or_insert_with(Vec::new);
             bytes.extend(chunk);
             bytes.push(b'\n');
@@ -129,27 +130,478 @@ fn parse_numbers_from_line_group(line_group: &str) -> Result<Vec<String>, Error>
         .collect())
 }
 
-pub fn convert(input: &str) -> Result<String, Error> {
+pub fn convert_detailed(input: &str) -> Result<Ocr, Error> {
     let mut num_newlines = 0;
-    let lines = input
+    let rows = input
         .split(|c| {
             let is_newline = c == '\n';
             num_newlines += usize::from(is_newline);
             is_newline && num_newlines % 4 == 0
         })
+        .enumerate()
+        .map(|(row, line_group_text)| {
+            Ok(parse_cells(line_group_text, 3)?
+                .into_iter()
+                .enumerate()
+                .map(|(column, grid)| Cell {
+                    digit: recognize(&grid),
+                    grid,
+                    position: (row, column),
+                })
+                .collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    num_newlines += 1;
+    if num_newlines % 4 != 0 {
+        return Err(Error::InvalidRowCount(num_newlines));
+    }
+
+    Ok(Ocr { rows })
+}
+
+pub fn convert(input: &str) -> Result<String, Error> {
+    let ocr = convert_detailed(input)?;
+    Ok(ocr
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    cell.digit
+                        .map_or_else(|| "?".to_string(), |digit| digit.to_string())
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Like [`convert`], but cells that match no glyph exactly are read as
+/// their nearest digit when its confidence is at least `min_confidence`,
+/// and '?' only below that.
+pub fn convert_fuzzy(input: &str, min_confidence: f64) -> Result<String, Error> {
+    let ocr = convert_detailed(input)?;
+    Ok(ocr
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell.digit {
+                    Some(digit) => digit.to_string(),
+                    None => {
+                        let best = &cell.candidates()[0];
+                        if best.confidence >= min_confidence {
+                            best.digit.to_string()
+                        } else {
+                            "?".to_string()
+                        }
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Like [`convert`], but cells that aren't digits are also checked
+/// against [`LETTER_GLYPHS`], so hex dumps and meter labels read as
+/// "12ab" instead of "12??".
+pub fn convert_alphanumeric(input: &str) -> Result<String, Error> {
+    let ocr = convert_detailed(input)?;
+    Ok(ocr
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell.digit {
+                    Some(digit) => char::from(b'0' + digit),
+                    None => LETTER_GLYPHS
+                        .iter()
+                        .find(|&&(_, glyph)| glyph == cell.grid)
+                        .map_or('?', |&(letter, _)| letter),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+const BLANK_GLYPH: &str = "   \n   \n   \n   ";
+
+fn glyph_for(c: char) -> &'static str {
+    if let Some(digit) = c.to_digit(10) {
+        DIGIT_GLYPHS[digit as usize]
+    } else {
+        LETTER_GLYPHS
+            .iter()
+            .find(|&&(letter, _)| letter == c)
+            .map_or(BLANK_GLYPH, |&(_, glyph)| glyph)
+    }
+}
+
+fn render_row(row: &str) -> Vec<String> {
+    let glyphs = row.chars().map(glyph_for).collect::<Vec<_>>();
+    (0..4)
+        .map(|line_index| {
+            glyphs
+                .iter()
+                .map(|glyph| glyph.split('\n').nth(line_index).unwrap())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Render a [`convert`]-style string ("123,456") back into its OCR grid:
+/// four lines of three columns per cell, one group of four lines per
+/// comma-separated row. Characters with no glyph (including '?') render
+/// as a blank cell, so `convert(&render(s))` round-trips.
+pub fn render(digits: &str) -> String {
+    digits
+        .split(',')
+        .flat_map(render_row)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A recognition font: a glyph table plus the cell dimensions it uses,
+/// so inputs aren't locked to the built-in 3x4 digits.
+pub struct Font {
+    cell_width: usize,
+    cell_height: usize,
+    glyphs: Vec<(char, String)>,
+}
+
+impl Default for Font {
+    /// The built-in 3x4 digit font used by [`convert`].
+    fn default() -> Self {
+        let mut font = Font::new(3, 4);
+        for (digit, &glyph) in DIGIT_GLYPHS.iter().enumerate() {
+            font = font.glyph(char::from(b'0' + digit as u8), glyph);
+        }
+        font
+    }
+}
+
+impl Font {
+    /// An empty font for `cell_width` x `cell_height` cells, including
+    /// any trailing blank separator line in the height.
+    pub fn new(cell_width: usize, cell_height: usize) -> Self {
+        assert!(cell_width > 0 && cell_height > 0, "degenerate cell size");
+        Font {
+            cell_width,
+            cell_height,
+            glyphs: Vec::new(),
+        }
+    }
+
+    /// Register a glyph. Panics if `grid` doesn't have exactly
+    /// `cell_height` rows of `cell_width` characters.
+    pub fn glyph(mut self, output: char, grid: &str) -> Self {
+        assert_eq!(
+            grid.split('\n').count(),
+            self.cell_height,
+            "glyph for {:?} has the wrong number of rows",
+            output
+        );
+        assert!(
+            grid.split('\n').all(|row| row.len() == self.cell_width),
+            "glyph for {:?} has a row of the wrong width",
+            output
+        );
+        self.glyphs.push((output, grid.to_string()));
+        self
+    }
+
+    fn recognize(&self, grid: &str) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| glyph == grid)
+            .map(|&(output, _)| output)
+    }
+}
+
+/// [`convert`], generalized over the glyph table and cell size in `font`.
+pub fn convert_with_font(input: &str, font: &Font) -> Result<String, Error> {
+    let mut num_newlines = 0;
+    let rows = input
+        .split(|c| {
+            let is_newline = c == '\n';
+            num_newlines += usize::from(is_newline);
+            is_newline && num_newlines % font.cell_height == 0
+        })
         .map(|line_group_text| {
-            Ok(parse_numbers_from_line_group(&line_group_text)?
+            Ok(parse_cells(line_group_text, font.cell_width)?
                 .iter()
-                .map(|number_text| parse(number_text))
-                .collect::<Vec<_>>()
-                .join(""))
+                .map(|grid| font.recognize(grid).unwrap_or('?'))
+                .collect::<String>())
         })
         .collect::<Result<Vec<_>, _>>()?;
 
     num_newlines += 1;
-    if num_newlines % 4 != 0 {
+    if !num_newlines.is_multiple_of(font.cell_height) {
         return Err(Error::InvalidRowCount(num_newlines));
     }
 
-    Ok(lines.join(","))
+    Ok(rows.join(","))
+}
+
+fn convert_row(line_group: &str) -> Result<String, Error> {
+    Ok(parse_cells(line_group, 3)?
+        .iter()
+        .map(|grid| recognize(grid).map_or('?', |digit| char::from(b'0' + digit)))
+        .collect())
+}
+
+/// Streaming [`convert`]: processes each four-line group as soon as it
+/// has been read, so a large scanned document never has to be held in
+/// memory all at once. A trailing newline on the last line is optional.
+pub fn convert_from_reader(reader: impl BufRead) -> Result<String, Error> {
+    let mut rows = Vec::new();
+    let mut group: Vec<String> = Vec::with_capacity(4);
+    let mut num_lines = 0;
+
+    for line in reader.lines() {
+        let line = line.map_err(|_| Error::Io)?;
+        num_lines += 1;
+        group.push(line);
+        if group.len() == 4 {
+            rows.push(convert_row(&group.join("\n"))?);
+            group.clear();
+        }
+    }
+
+    if num_lines == 0 || !group.is_empty() {
+        return Err(Error::InvalidRowCount(num_lines));
+    }
+
+    Ok(rows.join(","))
+}
+
+/// Validation status of one scanned account-number row.
+#[derive(Debug, PartialEq)]
+pub enum AccountStatus {
+    /// Nine digits recognized and the checksum holds.
+    Valid,
+    /// A glyph was unreadable, or the row isn't nine cells wide.
+    Illegible,
+    /// Every digit was read but the checksum failed.
+    BadChecksum,
+}
+
+/// The classic bank-OCR checksum: with digits named d9..d1 left to
+/// right, (1*d1 + 2*d2 + ... + 9*d9) must be divisible by 11.
+fn checksum_valid(digits: &str) -> bool {
+    digits
+        .chars()
+        .rev()
+        .zip(1u32..)
+        .map(|(c, weight)| weight * c.to_digit(10).unwrap())
+        .sum::<u32>()
+        .is_multiple_of(11)
+}
+
+fn account_status(row: &str) -> AccountStatus {
+    if row.len() != 9 || row.contains('?') {
+        AccountStatus::Illegible
+    } else if checksum_valid(row) {
+        AccountStatus::Valid
+    } else {
+        AccountStatus::BadChecksum
+    }
+}
+
+/// Treat each row of `input` as a nine-digit account number and validate
+/// it with the bank-OCR checksum.
+pub fn validate_accounts(input: &str) -> Result<Vec<(String, AccountStatus)>, Error> {
+    Ok(convert(input)?
+        .split(',')
+        .map(|row| (row.to_string(), account_status(row)))
+        .collect())
+}
+
+/// The kata's report format: one line per account number, suffixed with
+/// " ILL" or " ERR" where validation failed.
+pub fn account_report(input: &str) -> Result<String, Error> {
+    Ok(validate_accounts(input)?
+        .into_iter()
+        .map(|(number, status)| match status {
+            AccountStatus::Valid => number,
+            AccountStatus::Illegible => format!("{} ILL", number),
+            AccountStatus::BadChecksum => format!("{} ERR", number),
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Builder for a 3x4 recognizer extended with user-registered glyphs,
+/// so scanned meter readings full of '-' or '.' don't all come back as
+/// '?'. Starts from the built-in digit glyphs; use [`GlyphSet::empty`]
+/// to opt out of them.
+pub struct GlyphSet {
+    font: Font,
+}
+
+impl GlyphSet {
+    /// The built-in digits, ready for extension.
+    pub fn new() -> Self {
+        GlyphSet {
+            font: Font::default(),
+        }
+    }
+
+    /// No glyphs at all: every cell is '?' until some are registered.
+    pub fn empty() -> Self {
+        GlyphSet {
+            font: Font::new(3, 4),
+        }
+    }
+
+    /// Register a 3x4 pattern for `output`. Panics if `grid` isn't
+    /// exactly four rows of three characters.
+    pub fn register(self, output: char, grid: &str) -> Self {
+        GlyphSet {
+            font: self.font.glyph(output, grid),
+        }
+    }
+
+    pub fn convert(&self, input: &str) -> Result<String, Error> {
+        convert_with_font(input, &self.font)
+    }
+}
+
+impl Default for GlyphSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [`convert`] for numeric consumers: one `Vec` per row, one
+/// `Option<u8>` per cell, `None` where the glyph was unrecognized.
+pub fn convert_digits(input: &str) -> Result<Vec<Vec<Option<u8>>>, Error> {
+    Ok(convert_detailed(input)?
+        .rows
+        .into_iter()
+        .map(|row| row.into_iter().map(|cell| cell.digit).collect())
+        .collect())
+}
+
+/// [`convert`] for real-world scans: accepts Windows line endings, lines
+/// that come up short (they're padded with spaces to the widest line of
+/// their group), and a missing trailing blank line.
+pub fn convert_lenient(input: &str) -> Result<String, Error> {
+    let input = input.replace("\r\n", "\n");
+    let mut lines = input.split('\n').map(String::from).collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        return Ok(String::new());
+    }
+    while !lines.len().is_multiple_of(4) {
+        lines.push(String::new());
+    }
+
+    for group in lines.chunks_mut(4) {
+        let width = group
+            .iter()
+            .map(|line| line.len())
+            .max()
+            .unwrap()
+            .max(3)
+            .div_ceil(3)
+            * 3;
+        for line in group {
+            while line.len() < width {
+                line.push(' ');
+            }
+        }
+    }
+
+    convert(&lines.join("\n"))
+}
+
+/// Downsample a grayscale bitmap of one row of glyphs into the segment
+/// grid, then recognize it. The glyph row is assumed to span the full
+/// bitmap height, so each character cell is `height / 4` pixels square.
+/// Pixels darker than `threshold` count as ink; an inked cell becomes
+/// '_' in the middle column of a glyph and '|' in the outer columns.
+#[cfg(feature = "bitmap")]
+pub fn convert_bitmap(
+    width: usize,
+    height: usize,
+    pixels: &[u8],
+    threshold: u8,
+) -> Result<String, Error> {
+    if pixels.len() != width * height {
+        return Err(Error::InvalidPixelCount(pixels.len()));
+    }
+    if height == 0 || !height.is_multiple_of(4) {
+        return Err(Error::InvalidRowCount(height));
+    }
+    let scale = height / 4;
+    if !width.is_multiple_of(scale) || !(width / scale).is_multiple_of(3) {
+        return Err(Error::InvalidColumnCount(width));
+    }
+    let chars_wide = width / scale;
+
+    let lines = (0..4)
+        .map(|row| {
+            (0..chars_wide)
+                .map(|column| {
+                    let mut ink = 0usize;
+                    for y in row * scale..(row + 1) * scale {
+                        for x in column * scale..(column + 1) * scale {
+                            ink += usize::from(pixels[y * width + x] < threshold);
+                        }
+                    }
+                    if ink * 2 >= scale * scale {
+                        if column % 3 == 1 {
+                            '_'
+                        } else {
+                            '|'
+                        }
+                    } else {
+                        ' '
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    convert(&lines.join("\n"))
+}
+
+/// A malformed line group skipped by [`convert_partial`].
+#[derive(Debug, PartialEq)]
+pub struct SkippedGroup {
+    /// Zero-based range of input lines the group occupied.
+    pub lines: Range<usize>,
+    pub error: Error,
+}
+
+/// Recovery-mode [`convert`]: malformed four-line groups are skipped and
+/// recorded with their line spans, and every well-formed group is still
+/// recognized and returned.
+pub fn convert_partial(input: &str) -> (Vec<String>, Vec<SkippedGroup>) {
+    let lines = input.split('\n').collect::<Vec<_>>();
+    let mut rows = Vec::new();
+    let mut skipped = Vec::new();
+
+    for (index, group) in lines.chunks(4).enumerate() {
+        let start = index * 4;
+        let span = start..start + group.len();
+        let result = if group.len() < 4 {
+            Err(Error::InvalidRowCount(group.len()))
+        } else {
+            convert_row(&group.join("\n"))
+        };
+        match result {
+            Ok(row) => rows.push(row),
+            Err(error) => skipped.push(SkippedGroup { lines: span, error }),
+        }
+    }
+
+    (rows, skipped)
 }
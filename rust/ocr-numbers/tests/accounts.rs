@@ -0,0 +1,40 @@
+use ocr_numbers::{account_report, render, validate_accounts, AccountStatus};
+
+#[test]
+fn a_valid_account_number() {
+    let grid = render("457508000");
+    assert_eq!(
+        validate_accounts(&grid),
+        Ok(vec![("457508000".to_string(), AccountStatus::Valid)])
+    );
+}
+
+#[test]
+fn a_failing_checksum_is_err() {
+    let grid = render("664371495");
+    assert_eq!(account_report(&grid), Ok("664371495 ERR".to_string()));
+}
+
+#[test]
+fn unreadable_digits_are_ill() {
+    let grid = render("86110??36");
+    assert_eq!(account_report(&grid), Ok("86110??36 ILL".to_string()));
+}
+
+#[test]
+fn rows_that_are_not_nine_digits_are_ill() {
+    let grid = render("1234");
+    assert_eq!(
+        validate_accounts(&grid),
+        Ok(vec![("1234".to_string(), AccountStatus::Illegible)])
+    );
+}
+
+#[test]
+fn multi_row_reports() {
+    let grid = render("457508000,664371495");
+    assert_eq!(
+        account_report(&grid),
+        Ok("457508000\n664371495 ERR".to_string())
+    );
+}
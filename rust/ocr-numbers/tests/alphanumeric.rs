@@ -0,0 +1,37 @@
+use ocr_numbers::convert_alphanumeric;
+
+#[test]
+fn hex_digits() {
+    #[rustfmt::skip]
+    let input = " _     _     _  _ \n".to_string()
+              + "|_||_ |   _||_ |_ \n"
+              + "| ||_||_ |_||_ |  \n"
+              + "                  ";
+    assert_eq!(convert_alphanumeric(&input), Ok("abcdef".to_string()));
+}
+
+#[test]
+fn digits_still_win() {
+    let input = " _ \n".to_string() + "| |\n" + "|_|\n" + "   ";
+    assert_eq!(convert_alphanumeric(&input), Ok("0".to_string()));
+}
+
+#[test]
+fn mixed_rows() {
+    #[rustfmt::skip]
+    let input = "    _ \n".to_string()
+              + "  ||_ \n"
+              + "  ||  \n"
+              + "      \n"
+              + "      \n"
+              + "|_ | |\n"
+              + "|_||_|\n"
+              + "      ";
+    assert_eq!(convert_alphanumeric(&input), Ok("1f,bu".to_string()));
+}
+
+#[test]
+fn unknown_cells_stay_question_marks() {
+    let input = "___\n".to_string() + "___\n" + "___\n" + "   ";
+    assert_eq!(convert_alphanumeric(&input), Ok("?".to_string()));
+}
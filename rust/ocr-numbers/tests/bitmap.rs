@@ -0,0 +1,78 @@
+#![cfg(feature = "bitmap")]
+
+use ocr_numbers::{convert_bitmap, render, Error};
+
+/// Rasterize a glyph grid at `scale` pixels per character: ink is 0,
+/// background 255.
+fn rasterize(digits: &str, scale: usize) -> (usize, usize, Vec<u8>) {
+    let grid = render(digits);
+    let lines: Vec<&str> = grid.split('\n').collect();
+    let width = lines[0].len() * scale;
+    let height = lines.len() * scale;
+    let mut pixels = vec![255u8; width * height];
+    for (row, line) in lines.iter().enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            if c != ' ' {
+                for y in row * scale..(row + 1) * scale {
+                    for x in column * scale..(column + 1) * scale {
+                        pixels[y * width + x] = 0;
+                    }
+                }
+            }
+        }
+    }
+    (width, height, pixels)
+}
+
+#[test]
+fn recognizes_a_downscaled_row() {
+    let (width, height, pixels) = rasterize("1234567890", 3);
+    assert_eq!(
+        convert_bitmap(width, height, &pixels, 128),
+        Ok("1234567890".to_string())
+    );
+}
+
+#[test]
+fn single_pixel_cells_work() {
+    let (width, height, pixels) = rasterize("05", 1);
+    assert_eq!(
+        convert_bitmap(width, height, &pixels, 128),
+        Ok("05".to_string())
+    );
+}
+
+#[test]
+fn threshold_separates_ink_from_noise() {
+    let (width, height, mut pixels) = rasterize("8", 2);
+    for pixel in pixels.iter_mut() {
+        if *pixel == 255 {
+            *pixel = 200; // light scanning noise
+        }
+    }
+    assert_eq!(
+        convert_bitmap(width, height, &pixels, 128),
+        Ok("8".to_string())
+    );
+    // with a sloppy threshold everything is ink and nothing matches
+    assert_eq!(
+        convert_bitmap(width, height, &pixels, 255),
+        Ok("?".to_string())
+    );
+}
+
+#[test]
+fn bad_dimensions_are_errors() {
+    assert_eq!(
+        convert_bitmap(3, 5, &[255; 15], 128),
+        Err(Error::InvalidRowCount(5))
+    );
+    assert_eq!(
+        convert_bitmap(4, 4, &[255; 16], 128),
+        Err(Error::InvalidColumnCount(4))
+    );
+    assert_eq!(
+        convert_bitmap(3, 4, &[255; 10], 128),
+        Err(Error::InvalidPixelCount(10))
+    );
+}
@@ -0,0 +1,54 @@
+use ocr_numbers::{convert_detailed, Error};
+
+#[test]
+fn recognized_cells_carry_their_digit() {
+    let input = " _ \n".to_string() + "| |\n" + "|_|\n" + "   ";
+    let ocr = convert_detailed(&input).unwrap();
+    assert_eq!(ocr.rows.len(), 1);
+    let cell = &ocr.rows[0][0];
+    assert_eq!(cell.digit, Some(0));
+    assert_eq!(cell.position, (0, 0));
+    assert_eq!(cell.grid, " _ \n| |\n|_|\n   ");
+}
+
+#[test]
+fn garble_is_none_with_the_grid_preserved() {
+    let input = "   \n".to_string() + "| |\n" + "| |\n" + "   ";
+    let ocr = convert_detailed(&input).unwrap();
+    let cell = &ocr.rows[0][0];
+    assert_eq!(cell.digit, None);
+    assert_eq!(cell.grid, "   \n| |\n| |\n   ");
+}
+
+#[test]
+fn positions_cover_rows_and_columns() {
+    #[rustfmt::skip]
+    let input = "    _ \n".to_string()
+              + "  | _|\n"
+              + "  ||_ \n"
+              + "      \n"
+              + " _  _ \n"
+              + " _| _|\n"
+              + "|_  _|\n"
+              + "      ";
+    let ocr = convert_detailed(&input).unwrap();
+    let positions: Vec<Vec<_>> = ocr
+        .rows
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.position).collect())
+        .collect();
+    assert_eq!(positions, vec![vec![(0, 0), (0, 1)], vec![(1, 0), (1, 1)]]);
+    assert_eq!(ocr.rows[1][1].digit, Some(3));
+}
+
+#[test]
+fn errors_match_convert() {
+    assert_eq!(
+        convert_detailed("   \n  |\n  |"),
+        Err(Error::InvalidRowCount(3))
+    );
+    assert_eq!(
+        convert_detailed("    \n   |\n   |\n    "),
+        Err(Error::InvalidColumnCount(4))
+    );
+}
@@ -0,0 +1,30 @@
+use ocr_numbers::{convert_digits, render, Error};
+
+#[test]
+fn digits_come_back_typed() {
+    assert_eq!(
+        convert_digits(&render("120")),
+        Ok(vec![vec![Some(1), Some(2), Some(0)]])
+    );
+}
+
+#[test]
+fn unrecognized_cells_are_none() {
+    assert_eq!(
+        convert_digits(&render("1?3")),
+        Ok(vec![vec![Some(1), None, Some(3)]])
+    );
+}
+
+#[test]
+fn rows_stay_separate() {
+    assert_eq!(
+        convert_digits(&render("12,34")),
+        Ok(vec![vec![Some(1), Some(2)], vec![Some(3), Some(4)]])
+    );
+}
+
+#[test]
+fn errors_pass_through() {
+    assert_eq!(convert_digits("   "), Err(Error::InvalidRowCount(1)));
+}
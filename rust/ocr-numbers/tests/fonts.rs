@@ -0,0 +1,49 @@
+use ocr_numbers::{convert, convert_with_font, Error, Font};
+
+fn tall_font() -> Font {
+    // 4x3 cells: a boxier "1" and "0" than the built-in font
+    Font::new(4, 3)
+        .glyph('0', "|  |\n|__|\n    ")
+        .glyph('1', "   |\n   |\n    ")
+}
+
+#[test]
+fn the_default_font_matches_convert() {
+    let input = "    _ \n".to_string() + "  | _|\n" + "  ||_ \n" + "      ";
+    assert_eq!(convert_with_font(&input, &Font::default()), convert(&input));
+}
+
+#[test]
+fn custom_cell_sizes() {
+    let input = "   ||  |\n".to_string() + "   ||__|\n" + "        ";
+    assert_eq!(
+        convert_with_font(&input, &tall_font()),
+        Ok("10".to_string())
+    );
+}
+
+#[test]
+fn unknown_glyphs_become_question_marks() {
+    let input = "||||\n".to_string() + "||||\n" + "    ";
+    assert_eq!(convert_with_font(&input, &tall_font()), Ok("?".to_string()));
+}
+
+#[test]
+fn dimension_errors_use_the_font_cell_size() {
+    let two_lines = "   |\n   |";
+    assert_eq!(
+        convert_with_font(two_lines, &tall_font()),
+        Err(Error::InvalidRowCount(2))
+    );
+    let bad_columns = "   \n   \n   ";
+    assert_eq!(
+        convert_with_font(bad_columns, &tall_font()),
+        Err(Error::InvalidColumnCount(3))
+    );
+}
+
+#[test]
+#[should_panic(expected = "wrong number of rows")]
+fn misshapen_glyphs_are_rejected() {
+    let _ = Font::new(3, 4).glyph('x', "   \n   ");
+}
@@ -0,0 +1,47 @@
+use ocr_numbers::{convert_detailed, convert_fuzzy};
+
+// A zero missing its bottom-left stroke: one position away from '0'.
+fn smudged_zero() -> String {
+    " _ \n".to_string() + "| |\n" + " _|\n" + "   "
+}
+
+#[test]
+fn near_misses_are_read_as_the_closest_digit() {
+    assert_eq!(convert_fuzzy(&smudged_zero(), 0.9), Ok("0".to_string()));
+}
+
+#[test]
+fn the_threshold_still_rejects_bad_cells() {
+    assert_eq!(convert_fuzzy(&smudged_zero(), 0.95), Ok("?".to_string()));
+}
+
+#[test]
+fn exact_matches_are_untouched() {
+    let input = "   \n".to_string() + "  |\n" + "  |\n" + "   ";
+    assert_eq!(convert_fuzzy(&input, 1.0), Ok("1".to_string()));
+}
+
+#[test]
+fn candidates_rank_exact_matches_first() {
+    let input = " _ \n".to_string() + "|_|\n" + "|_|\n" + "   ";
+    let ocr = convert_detailed(&input).unwrap();
+    let ranked = ocr.rows[0][0].candidates();
+    assert_eq!(ranked[0].digit, 8);
+    assert_eq!(ranked[0].confidence, 1.0);
+    // 0, 6 and 9 are each one stroke away from 8
+    assert_eq!(
+        ranked[1..4].iter().map(|c| c.digit).collect::<Vec<_>>(),
+        vec![0, 6, 9]
+    );
+    assert!(ranked[1].confidence < 1.0);
+}
+
+#[test]
+fn confidence_scales_with_distance() {
+    let blank = "   \n".to_string() + "   \n" + "   \n" + "   ";
+    let ocr = convert_detailed(&blank).unwrap();
+    let ranked = ocr.rows[0][0].candidates();
+    // '1' uses only two strokes, so a blank cell is closest to it
+    assert_eq!(ranked[0].digit, 1);
+    assert!((ranked[0].confidence - (1.0 - 2.0 / 12.0)).abs() < 1e-9);
+}
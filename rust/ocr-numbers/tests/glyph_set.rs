@@ -0,0 +1,47 @@
+use ocr_numbers::{render, Error, GlyphSet};
+
+fn meter_font() -> GlyphSet {
+    GlyphSet::new()
+        .register('-', "   \n _ \n   \n   ")
+        .register('.', "   \n   \n . \n   ")
+}
+
+#[test]
+fn custom_glyphs_are_recognized() {
+    #[rustfmt::skip]
+    let input = " _    \n".to_string()
+              + " _|| |\n"
+              + "|_ | |\n"
+              + "      ";
+    assert_eq!(meter_font().convert(&input), Ok("2?".to_string()));
+}
+
+#[test]
+fn dashes_and_dots() {
+    #[rustfmt::skip]
+    let input = "      \n".to_string()
+              + " _    \n"
+              + "    . \n"
+              + "      ";
+    assert_eq!(meter_font().convert(&input), Ok("-.".to_string()));
+}
+
+#[test]
+fn builtin_digits_are_still_present() {
+    let grid = render("42");
+    assert_eq!(meter_font().convert(&grid), Ok("42".to_string()));
+}
+
+#[test]
+fn an_empty_set_recognizes_nothing() {
+    let grid = render("42");
+    assert_eq!(GlyphSet::empty().convert(&grid), Ok("??".to_string()));
+}
+
+#[test]
+fn dimension_errors_still_apply() {
+    assert_eq!(
+        meter_font().convert("    \n    \n    \n    "),
+        Err(Error::InvalidColumnCount(4))
+    );
+}
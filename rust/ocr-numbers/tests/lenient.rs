@@ -0,0 +1,34 @@
+use ocr_numbers::{convert, convert_lenient, Error};
+
+#[test]
+fn windows_line_endings() {
+    let input = " _ \r\n| |\r\n|_|\r\n   ";
+    assert!(convert(input).is_err());
+    assert_eq!(convert_lenient(input), Ok("0".to_string()));
+}
+
+#[test]
+fn missing_trailing_blank_line() {
+    let input = "   \n  |\n  |";
+    assert_eq!(convert(input), Err(Error::InvalidRowCount(3)));
+    assert_eq!(convert_lenient(input), Ok("1".to_string()));
+}
+
+#[test]
+fn short_lines_are_padded() {
+    // the "1" column drops trailing spaces, as editors tend to do
+    let input = " _\n _|  |\n _|  |\n";
+    assert_eq!(convert_lenient(input), Ok("31".to_string()));
+}
+
+#[test]
+fn strict_inputs_still_convert_identically() {
+    let input = " _  _ \n _| _|\n|_  _|\n      ";
+    assert_eq!(convert_lenient(input), convert(input));
+}
+
+#[test]
+fn blank_input_reads_as_nothing() {
+    assert_eq!(convert_lenient(""), Ok(String::new()));
+    assert_eq!(convert_lenient("\r\n\r\n"), Ok(String::new()));
+}
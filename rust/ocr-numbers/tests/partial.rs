@@ -0,0 +1,44 @@
+use ocr_numbers::{convert_partial, render, Error, SkippedGroup};
+
+#[test]
+fn clean_input_skips_nothing() {
+    let grid = render("12,34");
+    let (rows, skipped) = convert_partial(&grid);
+    assert_eq!(rows, vec!["12".to_string(), "34".to_string()]);
+    assert_eq!(skipped, vec![]);
+}
+
+#[test]
+fn bad_groups_are_skipped_with_their_spans() {
+    let grid = render("12") + "\nbad line\n\n\n\n" + &render("34");
+    let (rows, skipped) = convert_partial(&grid);
+    assert_eq!(rows, vec!["12".to_string(), "34".to_string()]);
+    assert_eq!(
+        skipped,
+        vec![SkippedGroup {
+            lines: 4..8,
+            error: Error::InvalidColumnCount(8),
+        }]
+    );
+}
+
+#[test]
+fn a_trailing_incomplete_group_is_recorded() {
+    let grid = render("5") + "\n   \n  |";
+    let (rows, skipped) = convert_partial(&grid);
+    assert_eq!(rows, vec!["5".to_string()]);
+    assert_eq!(
+        skipped,
+        vec![SkippedGroup {
+            lines: 4..6,
+            error: Error::InvalidRowCount(2),
+        }]
+    );
+}
+
+#[test]
+fn unreadable_but_well_formed_cells_still_convert() {
+    let (rows, skipped) = convert_partial(&render("1?3"));
+    assert_eq!(rows, vec!["1?3".to_string()]);
+    assert!(skipped.is_empty());
+}
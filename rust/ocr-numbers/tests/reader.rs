@@ -0,0 +1,55 @@
+use ocr_numbers::{convert, convert_from_reader, render, Error};
+use std::io::{self, Cursor, Read};
+
+#[test]
+fn matches_convert_on_multi_row_input() {
+    let grid = render("123,456,789");
+    assert_eq!(
+        convert_from_reader(Cursor::new(grid.clone())),
+        convert(&grid)
+    );
+}
+
+#[test]
+fn a_trailing_newline_is_tolerated() {
+    let grid = render("42") + "\n";
+    assert_eq!(convert_from_reader(Cursor::new(grid)), Ok("42".to_string()));
+}
+
+#[test]
+fn incomplete_groups_are_row_count_errors() {
+    let three_lines = "   \n  |\n  |";
+    assert_eq!(
+        convert_from_reader(Cursor::new(three_lines)),
+        Err(Error::InvalidRowCount(3))
+    );
+    assert_eq!(
+        convert_from_reader(Cursor::new("")),
+        Err(Error::InvalidRowCount(0))
+    );
+}
+
+#[test]
+fn column_errors_surface_per_group() {
+    let bad = "    \n   |\n   |\n    ";
+    assert_eq!(
+        convert_from_reader(Cursor::new(bad)),
+        Err(Error::InvalidColumnCount(4))
+    );
+}
+
+struct BrokenReader;
+
+impl Read for BrokenReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("scanner unplugged"))
+    }
+}
+
+#[test]
+fn read_failures_are_reported() {
+    assert_eq!(
+        convert_from_reader(io::BufReader::new(BrokenReader)),
+        Err(Error::Io)
+    );
+}
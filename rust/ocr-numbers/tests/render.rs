@@ -0,0 +1,40 @@
+use ocr_numbers::{convert, convert_alphanumeric, render};
+
+#[test]
+fn renders_a_single_digit() {
+    assert_eq!(render("0"), " _ \n| |\n|_|\n   ");
+}
+
+#[test]
+fn renders_a_row() {
+    #[rustfmt::skip]
+    let expected = "    _  _ \n".to_string()
+                 + "  | _| _|\n"
+                 + "  ||_  _|\n"
+                 + "         ";
+    assert_eq!(render("123"), expected);
+}
+
+#[test]
+fn round_trips_through_convert() {
+    for s in &["0", "1234567890", "123,456", "007,800", "?1?"] {
+        assert_eq!(convert(&render(s)).as_deref(), Ok(*s).as_deref(), "{}", s);
+    }
+}
+
+#[test]
+fn letters_round_trip_through_convert_alphanumeric() {
+    for s in &["12a,bff", "dead,beef"] {
+        assert_eq!(
+            convert_alphanumeric(&render(s)).as_deref(),
+            Ok(*s).as_deref(),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn unknown_characters_render_blank() {
+    assert_eq!(render("?"), "   \n   \n   \n   ");
+}
@@ -1,46 +1,58 @@
-#![feature(test)]
-extern crate parallel_letter_frequency;
-extern crate test;
-
+use criterion::{criterion_group, criterion_main, Criterion};
+use parallel_letter_frequency::{frequency_with, Backend};
 use std::collections::HashMap;
-use test::Bencher;
 
-#[bench]
-fn bench_tiny_parallel(b: &mut Bencher) {
-    let tiny = &["a"];
-    b.iter(|| parallel_letter_frequency::frequency(tiny, 3));
-}
+const BACKENDS: [(Backend, &str); 3] = [
+    (Backend::Stdlib, "stdlib"),
+    (Backend::Crossbeam, "crossbeam"),
+    (Backend::Rayon, "rayon"),
+];
 
-#[bench]
-fn bench_tiny_sequential(b: &mut Bencher) {
+fn bench_tiny(c: &mut Criterion) {
     let tiny = &["a"];
-    b.iter(|| frequency(tiny));
-}
-
-#[bench]
-fn bench_small_parallel(b: &mut Bencher) {
-    let texts = all_texts(1);
-    b.iter(|| parallel_letter_frequency::frequency(&texts, 3));
+    let mut group = c.benchmark_group("tiny");
+    for &(backend, name) in BACKENDS.iter() {
+        group.bench_function(name, |b| b.iter(|| frequency_with(tiny, 3, backend)));
+    }
+    group.bench_function("sequential", |b| b.iter(|| frequency(tiny)));
+    group.finish();
 }
 
-#[bench]
-fn bench_small_sequential(b: &mut Bencher) {
+fn bench_small(c: &mut Criterion) {
     let texts = all_texts(1);
-    b.iter(|| frequency(&texts));
+    let mut group = c.benchmark_group("small");
+    for &(backend, name) in BACKENDS.iter() {
+        group.bench_function(name, |b| b.iter(|| frequency_with(&texts, 3, backend)));
+    }
+    group.bench_function("sequential", |b| b.iter(|| frequency(&texts)));
+    group.finish();
 }
 
-#[bench]
-fn bench_large_parallel(b: &mut Bencher) {
+fn bench_large(c: &mut Criterion) {
     let texts = all_texts(30);
-    b.iter(|| parallel_letter_frequency::frequency(&texts, 3));
+    let mut group = c.benchmark_group("large");
+    for &(backend, name) in BACKENDS.iter() {
+        group.bench_function(name, |b| b.iter(|| frequency_with(&texts, 3, backend)));
+    }
+    group.bench_function("sequential", |b| b.iter(|| frequency(&texts)));
+    group.finish();
 }
 
-#[bench]
-fn bench_large_sequential(b: &mut Bencher) {
-    let texts = all_texts(30);
-    b.iter(|| frequency(&texts));
+fn bench_ascii(c: &mut Criterion) {
+    // Pure-ASCII corpus: exercises the byte-table fast path end to end
+    let line = "the quick brown fox jumps over the lazy dog";
+    let texts: Vec<&str> = std::iter::repeat_n(line, 1000).collect();
+    let mut group = c.benchmark_group("ascii");
+    for &(backend, name) in BACKENDS.iter() {
+        group.bench_function(name, |b| b.iter(|| frequency_with(&texts, 3, backend)));
+    }
+    group.bench_function("sequential", |b| b.iter(|| frequency(&texts)));
+    group.finish();
 }
 
+criterion_group!(benches, bench_tiny, bench_small, bench_large, bench_ascii);
+criterion_main!(benches);
+
 /// Simple sequential char frequency. Can it be beat?
 pub fn frequency(texts: &[&str]) -> HashMap<char, usize> {
     let mut map = HashMap::new();
@@ -1,7 +1,44 @@
+mod count {
+    use std::collections::HashMap;
+
+    /// Count the letters of `text` into `counts`.
+    ///
+    /// ASCII letters are tallied in a 256-entry byte table and merged into
+    /// the map once at the end, so predominantly-ASCII input never pays
+    /// for `char::to_lowercase` or per-character hashing; only non-ASCII
+    /// characters take the Unicode path.
+    pub fn count_chars(text: &str, counts: &mut HashMap<char, usize>) {
+        let mut table = [0usize; 256];
+        if text.is_ascii() {
+            for &byte in text.as_bytes() {
+                if byte.is_ascii_alphabetic() {
+                    table[byte.to_ascii_lowercase() as usize] += 1;
+                }
+            }
+        } else {
+            for c in text.chars() {
+                if c.is_ascii() {
+                    if c.is_ascii_alphabetic() {
+                        table[c.to_ascii_lowercase() as usize] += 1;
+                    }
+                } else if c.is_alphabetic() {
+                    for lc in c.to_lowercase() {
+                        *counts.entry(lc).or_default() += 1;
+                    }
+                }
+            }
+        }
+        for (byte, &count) in table.iter().enumerate() {
+            if count > 0 {
+                *counts.entry(byte as u8 as char).or_default() += count;
+            }
+        }
+    }
+}
+
 mod stdlib_impl {
     use std::{collections::HashMap, sync::mpsc};
 
-    #[allow(dead_code)]
     pub fn frequency(input: &[&str], worker_count: usize) -> HashMap<char, usize> {
         let (producer, consumer) = mpsc::channel::<HashMap<_, usize>>();
 
@@ -39,23 +76,17 @@ mod stdlib_impl {
             })
             .map(|chunk| {
                 let chunk = chunk
-                    .to_vec()
-                    .into_iter()
+                    .iter()
+                    .copied()
                     .map(ToOwned::to_owned)
-                    .collect::<Vec<_>>();
+                    .collect::<Vec<String>>();
                 let producer_clone = producer.clone();
                 std::thread::spawn(move || {
                     producer_clone
                         .send(chunk.into_iter().fold(
                             Default::default(),
                             move |mut counts, string| {
-                                for c in string.chars() {
-                                    if c.is_alphabetic() {
-                                        for lc in c.to_lowercase() {
-                                            *counts.entry(lc).or_default() += 1;
-                                        }
-                                    }
-                                }
+                                crate::count::count_chars(&string, &mut counts);
                                 counts
                             },
                         ))
@@ -89,7 +120,6 @@ mod crossbeam_impl {
 
     // This implementation is the same as the `raw` implementation, except that it
     // uses scoped threads to avoid copying the input.
-    #[allow(dead_code)]
     pub fn frequency(input: &[&str], worker_count: usize) -> HashMap<char, usize> {
         let (producer, consumer) = mpsc::channel::<HashMap<_, usize>>();
         let (result_tx, result_rx) = mpsc::sync_channel(1);
@@ -121,12 +151,8 @@ mod crossbeam_impl {
 
                     scope.spawn(move |_| {
                         let mut counts = HashMap::new();
-                        for c in chunk.iter().flat_map(|&string| string.chars()) {
-                            if c.is_alphabetic() {
-                                for lc in c.to_lowercase() {
-                                    *counts.entry(lc).or_default() += 1;
-                                }
-                            }
+                        for &string in chunk.iter() {
+                            crate::count::count_chars(string, &mut counts);
                         }
 
                         producer_clone.send(counts).unwrap();
@@ -149,7 +175,6 @@ mod rayon_impl {
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
     use std::collections::HashMap;
 
-    #[allow(dead_code)]
     pub fn frequency(input: &[&str], worker_count: usize) -> HashMap<char, usize> {
         rayon::ThreadPoolBuilder::new()
             .num_threads(worker_count)
@@ -159,13 +184,7 @@ mod rayon_impl {
                 input
                     .into_par_iter()
                     .fold(HashMap::new, |mut counts, &string| {
-                        for ch in string.chars() {
-                            if ch.is_alphabetic() {
-                                for lc in ch.to_lowercase() {
-                                    *counts.entry(lc).or_default() += 1;
-                                }
-                            }
-                        }
+                        crate::count::count_chars(string, &mut counts);
                         counts
                     })
                     .reduce(Default::default, |mut counts, map| {
@@ -178,6 +197,303 @@ mod rayon_impl {
     }
 }
 
+mod reader_impl {
+    use std::collections::HashMap;
+    use std::io::{self, Read};
+    use std::str;
+
+    /// How many bytes to pull from the reader per chunk. Large enough to
+    /// amortize channel traffic, small enough that backpressure keeps
+    /// memory use flat on arbitrarily large streams.
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Count letter frequencies from a byte stream without materializing
+    /// it. Chunks are cut on UTF-8 boundaries (bytes of a character split
+    /// across reads are carried into the next chunk) and handed to
+    /// `worker_count` threads over a bounded channel.
+    ///
+    /// Invalid UTF-8, including a stream that ends mid-character, is an
+    /// [`io::ErrorKind::InvalidData`] error.
+    pub fn frequency_from_reader<R: Read>(
+        mut reader: R,
+        worker_count: usize,
+    ) -> io::Result<HashMap<char, usize>> {
+        let worker_count = worker_count.max(1);
+        let (chunk_tx, chunk_rx) = crossbeam::channel::bounded::<String>(worker_count * 2);
+
+        let workers: Vec<_> = (0..worker_count)
+            .map(|_| {
+                let chunk_rx = chunk_rx.clone();
+                std::thread::spawn(move || {
+                    let mut counts = HashMap::<char, usize>::new();
+                    while let Ok(chunk) = chunk_rx.recv() {
+                        crate::count::count_chars(&chunk, &mut counts);
+                    }
+                    counts
+                })
+            })
+            .collect();
+        drop(chunk_rx);
+
+        let feed_result = feed_chunks(&mut reader, &chunk_tx);
+        // Hang up so the workers drain the channel and finish; join them
+        // even on a read error so no thread outlives the call.
+        drop(chunk_tx);
+        let mut freq = HashMap::new();
+        for worker in workers {
+            for (c, count) in worker.join().unwrap().into_iter() {
+                *freq.entry(c).or_default() += count;
+            }
+        }
+        feed_result?;
+        Ok(freq)
+    }
+
+    /// Read the stream chunk by chunk, sending each chunk's longest valid
+    /// UTF-8 prefix and carrying the remaining bytes into the next read
+    fn feed_chunks<R: Read>(
+        reader: &mut R,
+        chunk_tx: &crossbeam::channel::Sender<String>,
+    ) -> io::Result<()> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "stream is not valid UTF-8");
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        let mut carry = Vec::new();
+        loop {
+            let read = reader.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            carry.extend_from_slice(&buf[..read]);
+            let valid_len = match str::from_utf8(&carry) {
+                Ok(_) => carry.len(),
+                // A character split across reads is fine; anything else is
+                // a malformed stream
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(_) => return Err(invalid()),
+            };
+            let rest = carry.split_off(valid_len);
+            let chunk =
+                String::from_utf8(std::mem::replace(&mut carry, rest)).expect("prefix was checked");
+            if !chunk.is_empty() && chunk_tx.send(chunk).is_err() {
+                break;
+            }
+        }
+        if carry.is_empty() {
+            Ok(())
+        } else {
+            // The stream ended in the middle of a character
+            Err(invalid())
+        }
+    }
+}
+
+mod word_impl {
+    use std::{collections::HashMap, sync::mpsc};
+    use unicode_segmentation::UnicodeSegmentation;
+
+    /// How input lines are split into words.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Tokenizer {
+        /// Split on ASCII/Unicode whitespace, keeping punctuation attached
+        /// to the surrounding word
+        Whitespace,
+        /// Split on Unicode word boundaries (UAX #29), dropping
+        /// punctuation but keeping contractions like `don't` intact
+        UnicodeWords,
+    }
+
+    /// Count word frequencies with the default tokenization: Unicode word
+    /// boundaries and case folding.
+    pub fn word_frequency(input: &[&str], worker_count: usize) -> HashMap<String, usize> {
+        word_frequency_with(input, worker_count, Tokenizer::UnicodeWords, true)
+    }
+
+    /// Count word frequencies with explicit tokenization and case-folding
+    /// choices, using the same scoped-thread map-reduce as [`frequency`].
+    ///
+    /// [`frequency`]: crate::frequency
+    pub fn word_frequency_with(
+        input: &[&str],
+        worker_count: usize,
+        tokenizer: Tokenizer,
+        fold_case: bool,
+    ) -> HashMap<String, usize> {
+        let (producer, consumer) = mpsc::channel::<HashMap<String, usize>>();
+        let (result_tx, result_rx) = mpsc::sync_channel(1);
+        let nchunks = {
+            let nchunks = input.len() / worker_count;
+            if nchunks == 0 {
+                input.len().max(1)
+            } else {
+                nchunks
+            }
+        };
+
+        crossbeam::thread::scope(move |scope| {
+            let consumer_thread = scope.spawn(move |_| {
+                let mut freq = HashMap::new();
+                while let Ok(map) = consumer.recv() {
+                    for (word, count) in map.into_iter() {
+                        *freq.entry(word).or_default() += count;
+                    }
+                }
+
+                result_tx.send(freq).unwrap();
+            });
+
+            input
+                .chunks(nchunks)
+                .map(|chunk| {
+                    let producer_clone = producer.clone();
+
+                    scope.spawn(move |_| {
+                        let mut counts = HashMap::new();
+                        for &line in chunk.iter() {
+                            count_words(line, tokenizer, fold_case, &mut counts);
+                        }
+
+                        producer_clone.send(counts).unwrap();
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .for_each(|producer_thread| producer_thread.join().unwrap());
+
+            drop(producer);
+
+            consumer_thread.join().unwrap();
+            result_rx.recv().unwrap()
+        })
+        .unwrap()
+    }
+
+    /// Tokenize one line and add its words to `counts`
+    fn count_words(
+        line: &str,
+        tokenizer: Tokenizer,
+        fold_case: bool,
+        counts: &mut HashMap<String, usize>,
+    ) {
+        let tokens: Box<dyn Iterator<Item = &str>> = match tokenizer {
+            Tokenizer::Whitespace => Box::new(line.split_whitespace()),
+            Tokenizer::UnicodeWords => Box::new(line.unicode_words()),
+        };
+        for token in tokens {
+            let word = if fold_case {
+                token.to_lowercase()
+            } else {
+                token.to_string()
+            };
+            *counts.entry(word).or_default() += 1;
+        }
+    }
+}
+
+mod topk_impl {
+    use std::{cmp::Reverse, collections::BinaryHeap};
+
+    /// The `k` most frequent letters of `input`, most frequent first.
+    ///
+    /// Ties are broken by the letter itself (ascending), so the output is
+    /// deterministic. The reduce phase pushes each merged entry through a
+    /// k-bounded min-heap instead of sorting the whole frequency map.
+    pub fn top_k(input: &[&str], worker_count: usize, k: usize) -> Vec<(char, usize)> {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for (ch, count) in crate::crossbeam_impl::frequency(input, worker_count) {
+            heap.push(Reverse((count, Reverse(ch))));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((count, Reverse(ch)))| (ch, count))
+            .collect()
+    }
+}
+
+mod str_impl {
+    use std::collections::HashMap;
+
+    /// Split `input` into at most `worker_count` chunks of roughly equal
+    /// byte length, nudging each cut forward to the next char boundary so
+    /// no worker ever sees half a character.
+    fn split_chunks(input: &str, worker_count: usize) -> Vec<&str> {
+        let target = (input.len() / worker_count).max(1);
+        let mut chunks = Vec::with_capacity(worker_count);
+        let mut rest = input;
+        while !rest.is_empty() {
+            if chunks.len() + 1 == worker_count || rest.len() <= target {
+                chunks.push(rest);
+                break;
+            }
+            let mut cut = target;
+            while !rest.is_char_boundary(cut) {
+                cut += 1;
+            }
+            let (chunk, tail) = rest.split_at(cut);
+            chunks.push(chunk);
+            rest = tail;
+        }
+        chunks
+    }
+
+    /// Count letter frequencies of one large string by slicing it across
+    /// `worker_count` scoped threads, instead of chunking by slice element
+    /// like the `&[&str]` entry points do.
+    pub fn frequency_str(input: &str, worker_count: usize) -> HashMap<char, usize> {
+        let chunks = split_chunks(input, worker_count.max(1));
+        crossbeam::thread::scope(|scope| {
+            chunks
+                .into_iter()
+                .map(|chunk| {
+                    scope.spawn(move |_| {
+                        let mut counts = HashMap::new();
+                        crate::count::count_chars(chunk, &mut counts);
+                        counts
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .fold(HashMap::new(), |mut freq, worker| {
+                    for (ch, count) in worker.join().unwrap() {
+                        *freq.entry(ch).or_default() += count;
+                    }
+                    freq
+                })
+        })
+        .unwrap()
+    }
+}
+
 pub use crossbeam_impl::frequency;
-// pub use rayon_impl::frequency;
-// pub use stdlib_impl::frequency;
+pub use reader_impl::frequency_from_reader;
+pub use str_impl::frequency_str;
+pub use topk_impl::top_k;
+pub use word_impl::{word_frequency, word_frequency_with, Tokenizer};
+
+/// The parallelization strategy used by [`frequency_with`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backend {
+    /// Plain `std::thread` workers, each copying its chunk of the input
+    Stdlib,
+    /// Crossbeam scoped threads borrowing the input directly
+    Crossbeam,
+    /// A dedicated rayon thread pool
+    Rayon,
+}
+
+/// Count letter frequencies with an explicitly chosen backend, so callers
+/// and benchmarks can compare strategies at runtime instead of editing a
+/// `pub use`. [`frequency`] remains the default (crossbeam) entry point.
+pub fn frequency_with(
+    input: &[&str],
+    worker_count: usize,
+    backend: Backend,
+) -> std::collections::HashMap<char, usize> {
+    match backend {
+        Backend::Stdlib => stdlib_impl::frequency(input, worker_count),
+        Backend::Crossbeam => crossbeam_impl::frequency(input, worker_count),
+        Backend::Rayon => rayon_impl::frequency(input, worker_count),
+    }
+}
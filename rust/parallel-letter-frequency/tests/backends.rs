@@ -0,0 +1,48 @@
+use parallel_letter_frequency::{frequency, frequency_with, Backend};
+
+const BACKENDS: [Backend; 3] = [Backend::Stdlib, Backend::Crossbeam, Backend::Rayon];
+
+#[test]
+fn all_backends_agree_with_the_default() {
+    let input = &["The quick brown fox", "jumps over the LAZY dog", "åäö"];
+    let expected = frequency(input, 4);
+    for &backend in BACKENDS.iter() {
+        assert_eq!(frequency_with(input, 4, backend), expected);
+    }
+}
+
+#[test]
+fn all_backends_handle_empty_input() {
+    for &backend in BACKENDS.iter() {
+        assert!(frequency_with(&[], 3, backend).is_empty());
+    }
+}
+
+#[test]
+fn all_backends_work_with_a_single_worker() {
+    for &backend in BACKENDS.iter() {
+        let counts = frequency_with(&["aaa", "bb"], 1, backend);
+        assert_eq!(counts.get(&'a'), Some(&3));
+        assert_eq!(counts.get(&'b'), Some(&2));
+    }
+}
+
+#[test]
+fn all_backends_work_with_more_workers_than_lines() {
+    for &backend in BACKENDS.iter() {
+        let counts = frequency_with(&["ab"], 16, backend);
+        assert_eq!(counts.get(&'a'), Some(&1));
+        assert_eq!(counts.get(&'b'), Some(&1));
+    }
+}
+
+#[test]
+fn ascii_and_unicode_letters_mix_correctly() {
+    for &backend in BACKENDS.iter() {
+        let counts = frequency_with(&["AaÄä b"], 2, backend);
+        assert_eq!(counts.get(&'a'), Some(&2));
+        assert_eq!(counts.get(&'ä'), Some(&2));
+        assert_eq!(counts.get(&'b'), Some(&1));
+        assert_eq!(counts.get(&' '), None);
+    }
+}
@@ -0,0 +1,60 @@
+use parallel_letter_frequency::{frequency, frequency_from_reader};
+use std::io::{self, Cursor, Read};
+
+#[test]
+fn matches_the_slice_api_on_the_same_text() {
+    let lines = ["The quick brown fox", "jumps over the LAZY dog", "åäö"];
+    let text = lines.join("\n");
+    let expected = frequency(&lines, 4);
+    let counts = frequency_from_reader(Cursor::new(text), 4).unwrap();
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn an_empty_stream_yields_an_empty_map() {
+    let counts = frequency_from_reader(Cursor::new(""), 3).unwrap();
+    assert!(counts.is_empty());
+}
+
+#[test]
+fn multibyte_characters_split_across_chunks_are_counted_once() {
+    // Two-byte characters at an odd repeat count guarantee that chunk
+    // boundaries fall inside characters somewhere in the stream.
+    let text = "ä".repeat(100_001);
+    let counts = frequency_from_reader(Cursor::new(text), 4).unwrap();
+    assert_eq!(counts.get(&'ä'), Some(&100_001));
+    assert_eq!(counts.len(), 1);
+}
+
+/// A reader that returns one byte at a time, so every multibyte character
+/// is split across reads.
+struct OneByteReader<R>(R);
+
+impl<R: Read> Read for OneByteReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let take = buf.len().min(1);
+        self.0.read(&mut buf[..take])
+    }
+}
+
+#[test]
+fn single_byte_reads_still_respect_utf8_boundaries() {
+    let reader = OneByteReader(Cursor::new("åäö".repeat(10)));
+    let counts = frequency_from_reader(reader, 2).unwrap();
+    assert_eq!(counts.get(&'å'), Some(&10));
+    assert_eq!(counts.get(&'ä'), Some(&10));
+    assert_eq!(counts.get(&'ö'), Some(&10));
+}
+
+#[test]
+fn invalid_utf8_is_an_error() {
+    let error = frequency_from_reader(Cursor::new(&[b'a', 0xff, b'b'][..]), 2).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn a_stream_ending_mid_character_is_an_error() {
+    // First byte of a two-byte encoding, then EOF
+    let error = frequency_from_reader(Cursor::new(&[0xc3][..]), 2).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,36 @@
+use parallel_letter_frequency::{frequency, frequency_str};
+
+#[test]
+fn matches_the_slice_based_api() {
+    let text = "Freude schöner Götterfunken, Tochter aus Elysium";
+    assert_eq!(frequency_str(text, 4), frequency(&[text], 4));
+}
+
+#[test]
+fn multibyte_chars_survive_chunk_boundaries() {
+    let text = "ä".repeat(1001);
+    let counts = frequency_str(&text, 7);
+    assert_eq!(counts.get(&'ä'), Some(&1001));
+    assert_eq!(counts.len(), 1);
+}
+
+#[test]
+fn results_are_independent_of_worker_count() {
+    let text = "the quick brown fox jumps over the lazy dog".repeat(50);
+    let baseline = frequency_str(&text, 1);
+    for workers in 2..=8 {
+        assert_eq!(frequency_str(&text, workers), baseline);
+    }
+}
+
+#[test]
+fn more_workers_than_bytes() {
+    let counts = frequency_str("ab", 16);
+    assert_eq!(counts.get(&'a'), Some(&1));
+    assert_eq!(counts.get(&'b'), Some(&1));
+}
+
+#[test]
+fn empty_input_is_empty() {
+    assert!(frequency_str("", 4).is_empty());
+}
@@ -0,0 +1,30 @@
+use parallel_letter_frequency::top_k;
+
+#[test]
+fn top_k_orders_by_descending_count() {
+    let ranked = top_k(&["aaa bb c"], 2, 3);
+    assert_eq!(ranked, vec![('a', 3), ('b', 2), ('c', 1)]);
+}
+
+#[test]
+fn ties_break_on_the_letter_ascending() {
+    let ranked = top_k(&["ba", "ab"], 2, 2);
+    assert_eq!(ranked, vec![('a', 2), ('b', 2)]);
+}
+
+#[test]
+fn k_larger_than_the_alphabet_returns_everything() {
+    let ranked = top_k(&["xyz"], 2, 100);
+    assert_eq!(ranked, vec![('x', 1), ('y', 1), ('z', 1)]);
+}
+
+#[test]
+fn k_zero_is_empty() {
+    assert_eq!(top_k(&["abc"], 2, 0), vec![]);
+}
+
+#[test]
+fn only_the_k_best_survive() {
+    let ranked = top_k(&["aaaa bbb cc d"], 3, 2);
+    assert_eq!(ranked, vec![('a', 4), ('b', 3)]);
+}
@@ -0,0 +1,54 @@
+use parallel_letter_frequency::{word_frequency, word_frequency_with, Tokenizer};
+
+#[test]
+fn counts_words_across_lines() {
+    let input = &["the quick brown fox", "jumps over the lazy dog"];
+    let counts = word_frequency(input, 3);
+    assert_eq!(counts.get("the"), Some(&2));
+    assert_eq!(counts.get("fox"), Some(&1));
+    assert_eq!(counts.len(), 8);
+}
+
+#[test]
+fn case_is_folded_by_default() {
+    let counts = word_frequency(&["The THE the"], 2);
+    assert_eq!(counts.get("the"), Some(&3));
+    assert_eq!(counts.len(), 1);
+}
+
+#[test]
+fn case_folding_can_be_disabled() {
+    let counts = word_frequency_with(&["The THE the"], 2, Tokenizer::UnicodeWords, false);
+    assert_eq!(counts.get("The"), Some(&1));
+    assert_eq!(counts.get("THE"), Some(&1));
+    assert_eq!(counts.get("the"), Some(&1));
+}
+
+#[test]
+fn unicode_word_boundaries_drop_punctuation_but_keep_contractions() {
+    let counts = word_frequency(&["Don't panic, don't!"], 2);
+    assert_eq!(counts.get("don't"), Some(&2));
+    assert_eq!(counts.get("panic"), Some(&1));
+    assert_eq!(counts.len(), 2);
+}
+
+#[test]
+fn whitespace_tokenization_keeps_punctuation_attached() {
+    let counts = word_frequency_with(&["hello, world!"], 2, Tokenizer::Whitespace, true);
+    assert_eq!(counts.get("hello,"), Some(&1));
+    assert_eq!(counts.get("world!"), Some(&1));
+}
+
+#[test]
+fn results_are_independent_of_worker_count() {
+    let input = &["one two three", "two three", "three"];
+    let expected = word_frequency(input, 1);
+    for workers in 2..6 {
+        assert_eq!(word_frequency(input, workers), expected);
+    }
+}
+
+#[test]
+fn empty_input_gives_an_empty_map() {
+    assert!(word_frequency(&[], 4).is_empty());
+}
@@ -1,9 +1,9 @@
-#![feature(map_into_keys_values)]
-
 /// Given a list of poker hands, return a list of those hands which win.
 ///
 /// Note the type signature: this function should return _the same_ reference to
 /// the winning hand(s) as were passed in, not reconstructed strings which happen to be equal.
+pub mod render;
+
 use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
@@ -12,7 +12,7 @@ use std::{
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum Value {
+pub enum Value {
     LowAce = 0,
     Two = 1,
     Three = 2,
@@ -46,7 +46,7 @@ enum Suit {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Card {
+pub struct Card {
     value: Value,
     suit: Suit,
 }
@@ -63,10 +63,10 @@ impl PartialOrd for Card {
 impl FromStr for Card {
     type Err = Infallible;
     fn from_str(card: &str) -> Result<Self, Self::Err> {
-        assert!(card.len() >= 2);
-        assert!(card.len() <= 3);
+        let suit_char = card.chars().last().expect("empty card");
+        let value_str = &card[..card.len() - suit_char.len_utf8()];
 
-        let value = match &card[..card.len() - 1] {
+        let value = match value_str {
             "2" => Value::Two,
             "3" => Value::Three,
             "4" => Value::Four,
@@ -75,7 +75,7 @@ impl FromStr for Card {
             "7" => Value::Seven,
             "8" => Value::Eight,
             "9" => Value::Nine,
-            "10" => Value::Ten,
+            "10" | "T" => Value::Ten,
             "J" => Value::Jack,
             "Q" => Value::Queen,
             "K" => Value::King,
@@ -83,11 +83,11 @@ impl FromStr for Card {
             s => panic!("invalid face value: {}", s),
         };
 
-        let suit = match card.chars().last().unwrap() {
-            'S' => Suit::Spades,
-            'C' => Suit::Clubs,
-            'D' => Suit::Diamonds,
-            'H' => Suit::Hearts,
+        let suit = match suit_char {
+            'S' | '\u{2660}' => Suit::Spades,
+            'C' | '\u{2663}' => Suit::Clubs,
+            'D' | '\u{2666}' => Suit::Diamonds,
+            'H' | '\u{2665}' => Suit::Hearts,
             c => panic!("invalid suit: {}", c),
         };
 
@@ -95,19 +95,86 @@ impl FromStr for Card {
     }
 }
 
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Value::Two => "2",
+            Value::Three => "3",
+            Value::Four => "4",
+            Value::Five => "5",
+            Value::Six => "6",
+            Value::Seven => "7",
+            Value::Eight => "8",
+            Value::Nine => "9",
+            Value::Ten => "10",
+            Value::Jack => "J",
+            Value::Queen => "Q",
+            Value::King => "K",
+            Value::LowAce | Value::HighAce => "A",
+        };
+        f.write_str(value)
+    }
+}
+
+impl std::fmt::Display for Suit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let suit = match self {
+            Suit::Spades => "S",
+            Suit::Clubs => "C",
+            Suit::Diamonds => "D",
+            Suit::Hearts => "H",
+        };
+        f.write_str(suit)
+    }
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value, self.suit)
+    }
+}
+
+impl std::fmt::Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut cards = self.cards.iter();
+        if let Some(card) = cards.next() {
+            write!(f, "{}", card)?;
+            for card in cards {
+                write!(f, " {}", card)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
 }
 
 impl Hand {
-    fn rank(self) -> HandRank {
+    pub fn rank(self) -> HandRank {
+        self.rank_with(RankingRules::High)
+    }
+
+    pub fn rank_with(self, rules: RankingRules) -> HandRank {
+        // In deuce-to-seven the ace is always high, so the wheel
+        // (A 2 3 4 5) is just ace-high, not a straight — suited or not.
+        let (straight_flush_check, straight_check) = match rules {
+            RankingRules::DeuceToSevenLow => (
+                straight_flush_ace_high_only as Check,
+                straight_ace_high_only as Check,
+            ),
+            RankingRules::High | RankingRules::EightOrBetterLow => {
+                (straight_flush as Check, straight as Check)
+            }
+        };
         for check in &[
-            straight_flush,
+            straight_flush_check,
             four_of_a_kind,
             full_house,
             flush,
-            straight,
+            straight_check,
             three_of_a_kind,
             two_pair,
             one_pair,
@@ -120,8 +187,21 @@ impl Hand {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum HandRank {
+/// The order in which showdown hands are ranked.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RankingRules {
+    /// Conventional high rules: the best high hand wins.
+    High,
+    /// Deuce-to-seven lowball: the worst high hand wins, aces are always
+    /// high, and straights and flushes count against the hand.
+    DeuceToSevenLow,
+    /// Eight-or-better low for hi-lo split games: five distinct ranks of
+    /// eight or lower, aces low, straights and flushes ignored.
+    EightOrBetterLow,
+}
+
+#[derive(Debug, Clone)]
+pub enum HandRank {
     HighCard(Hand),
     OnePair(Hand),
     TwoPair(Hand),
@@ -133,44 +213,6 @@ enum HandRank {
     StraightFlush(Hand),
 }
 
-fn order_by_rank(lhs: &Hand, rhs: &Hand) -> Option<Ordering> {
-    order_by_rank_cards(&lhs.cards, &rhs.cards)
-}
-
-fn order_by_rank_cards(lhs: &[Card], rhs: &[Card]) -> Option<Ordering> {
-    order_by_rank_values(
-        lhs.iter().map(|card| card.value),
-        rhs.iter().map(|card| card.value),
-    )
-}
-
-fn order_by_rank_values(
-    lhs: impl Iterator<Item = Value>,
-    rhs: impl Iterator<Item = Value>,
-) -> Option<Ordering> {
-    lhs.zip(rhs)
-        .map(|(left, right)| left.partial_cmp(&right).unwrap_or(Ordering::Equal))
-        .find(|&ordering| ordering != Ordering::Equal)
-}
-
-fn order_by_rank_grouped(lhs: &Hand, rhs: &Hand, cmp_order: &[usize]) -> Option<Ordering> {
-    let mut lhs_accounting = count_values(lhs);
-    let mut rhs_accounting = count_values(rhs);
-
-    for &order in cmp_order.iter() {
-        match lhs_accounting
-            .remove(&order)
-            .unwrap()
-            .cmp(&rhs_accounting.remove(&order).unwrap())
-        {
-            Ordering::Equal => continue,
-            otherwise => return Some(otherwise),
-        }
-    }
-
-    order_by_rank_values(lhs_accounting.into_values(), rhs_accounting.into_values())
-}
-
 fn has_card_value(cards: &[Card], value: Value) -> bool {
     cards.iter().any(|card| card.value == value)
 }
@@ -190,73 +232,243 @@ fn ace_low_straight_rank(cards: &[Card]) -> Vec<Card> {
     result
 }
 
-fn order_by_rank_straight(lhs: &[Card], rhs: &[Card]) -> Option<Ordering> {
-    let lhs = match (
-        has_card_value(lhs, Value::HighAce),
-        has_card_value(lhs, Value::Two),
-        has_card_value(lhs, Value::King),
-    ) {
-        (true, true, false) => ace_low_straight_rank(lhs),
-        _ => lhs.to_owned(),
-    };
-    let rhs = match (
-        has_card_value(rhs, Value::HighAce),
-        has_card_value(rhs, Value::Two),
-        has_card_value(rhs, Value::King),
-    ) {
-        (true, true, false) => ace_low_straight_rank(rhs),
-        _ => rhs.to_owned(),
-    };
-    order_by_rank_cards(&lhs, &rhs)
-}
-
 impl PartialOrd for HandRank {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(match (self, other) {
-            (Self::StraightFlush(lhs), Self::StraightFlush(rhs)) => {
-                return order_by_rank(&lhs, &rhs);
-            }
-            (Self::FourOfAKind(lhs), Self::FourOfAKind(rhs)) => {
-                return order_by_rank_grouped(&lhs, &rhs, &[4, 1]);
-            }
-            (Self::FullHouse(lhs), Self::FullHouse(rhs)) => {
-                return order_by_rank_grouped(&lhs, &rhs, &[3, 2]);
-            }
-            (Self::Flush(lhs), Self::Flush(rhs)) => return order_by_rank(&lhs, &rhs),
-            (Self::Straight(lhs), Self::Straight(rhs)) => {
-                return order_by_rank_straight(&lhs.cards, &rhs.cards);
-            }
-            (Self::ThreeOfAKind(lhs), Self::ThreeOfAKind(rhs)) => {
-                return order_by_rank(&lhs, &rhs);
-            }
-            (Self::TwoPair(lhs), Self::TwoPair(rhs)) => return order_by_rank(&lhs, &rhs),
-            (Self::OnePair(lhs), Self::OnePair(rhs)) => return order_by_rank(&lhs, &rhs),
-            (Self::HighCard(lhs), Self::HighCard(rhs)) => return order_by_rank(&lhs, &rhs),
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HandRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+// Equality must agree with `Ord` (`a == b` iff `cmp == Equal`), so it
+// compares the same canonical key: equally ranked hands of different
+// suits are equal, not merely non-ordered.
+impl PartialEq for HandRank {
+    fn eq(&self, other: &Self) -> bool {
+        self.sort_key() == other.sort_key()
+    }
+}
 
-            (Self::StraightFlush(_), _) => Ordering::Greater,
-            (_, Self::StraightFlush(_)) => Ordering::Less,
+impl Eq for HandRank {}
+
+impl HandRank {
+    fn hand(&self) -> &Hand {
+        match self {
+            Self::HighCard(hand)
+            | Self::OnePair(hand)
+            | Self::TwoPair(hand)
+            | Self::ThreeOfAKind(hand)
+            | Self::Straight(hand)
+            | Self::Flush(hand)
+            | Self::FullHouse(hand)
+            | Self::FourOfAKind(hand)
+            | Self::StraightFlush(hand) => hand,
+        }
+    }
+
+    fn compare(&self, other: &Self, rules: RankingRules) -> Option<Ordering> {
+        match rules {
+            RankingRules::High => Some(self.cmp(other)),
+            RankingRules::DeuceToSevenLow => Some(other.cmp(self)),
+            RankingRules::EightOrBetterLow => eight_or_better_cmp(self.hand(), other.hand()),
+        }
+    }
 
-            (Self::FourOfAKind(_), _) => Ordering::Greater,
-            (_, Self::FourOfAKind(_)) => Ordering::Less,
+    /// A canonical sortable key: the category's rank followed by its
+    /// tie-break values, most significant first. Comparing keys
+    /// lexicographically is a total order over hands under high rules.
+    fn sort_key(&self) -> (usize, Vec<Value>) {
+        (self.category_index(), self.tiebreak_values())
+    }
+
+    fn category(&self) -> &'static str {
+        match self {
+            Self::HighCard(_) => "high card",
+            Self::OnePair(_) => "pair",
+            Self::TwoPair(_) => "two pair",
+            Self::ThreeOfAKind(_) => "three of a kind",
+            Self::Straight(_) => "straight",
+            Self::Flush(_) => "flush",
+            Self::FullHouse(_) => "full house",
+            Self::FourOfAKind(_) => "four of a kind",
+            Self::StraightFlush(_) => "straight flush",
+        }
+    }
+
+    fn category_index(&self) -> usize {
+        match self {
+            Self::HighCard(_) => 0,
+            Self::OnePair(_) => 1,
+            Self::TwoPair(_) => 2,
+            Self::ThreeOfAKind(_) => 3,
+            Self::Straight(_) => 4,
+            Self::Flush(_) => 5,
+            Self::FullHouse(_) => 6,
+            Self::FourOfAKind(_) => 7,
+            Self::StraightFlush(_) => 8,
+        }
+    }
 
-            (Self::FullHouse(_), _) => Ordering::Greater,
-            (_, Self::FullHouse(_)) => Ordering::Less,
+    /// The card values that break ties within this category, most
+    /// significant first: grouped values (quads, trips, pairs) before
+    /// kickers, with the wheel's ace counted low.
+    fn tiebreak_values(&self) -> Vec<Value> {
+        let cards = &self.hand().cards;
+        match self {
+            Self::Straight(_) | Self::StraightFlush(_) => {
+                match (
+                    has_card_value(cards, Value::HighAce),
+                    has_card_value(cards, Value::Two),
+                    has_card_value(cards, Value::King),
+                ) {
+                    (true, true, false) => ace_low_straight_rank(cards)
+                        .iter()
+                        .map(|card| card.value)
+                        .collect(),
+                    _ => cards.iter().map(|card| card.value).collect(),
+                }
+            }
+            Self::HighCard(_) | Self::Flush(_) => cards.iter().map(|card| card.value).collect(),
+            _ => {
+                let mut groups = value_counts(self.hand()).into_iter().collect::<Vec<_>>();
+                groups.sort_by_key(|&(value, count)| std::cmp::Reverse((count, value)));
+                groups.into_iter().map(|(value, _)| value).collect()
+            }
+        }
+    }
 
-            (Self::Flush(_), _) => Ordering::Greater,
-            (_, Self::Flush(_)) => Ordering::Less,
+    /// A monotone strength score in `[0, 1)`: comparing scores agrees with
+    /// comparing ranks under high rules, and equal ranks score equally.
+    ///
+    /// The score is the hand's sort key read as a base-14 numeral --
+    /// category first, then up to five tie-break values -- scaled by the
+    /// smallest power of the base that bounds every key.
+    pub fn strength(&self) -> f64 {
+        const BASE: u64 = 14;
+        let (category, values) = self.sort_key();
+        let mut encoded = category as u64;
+        let mut digits = 0;
+        for value in values {
+            encoded = encoded * BASE + value as u64;
+            digits += 1;
+        }
+        for _ in digits..5 {
+            encoded *= BASE;
+        }
+        encoded as f64 / (9 * BASE.pow(5)) as f64
+    }
 
-            (Self::Straight(_), _) => Ordering::Greater,
-            (_, Self::Straight(_)) => Ordering::Less,
+    /// Report which category or kicker decides the comparison between two
+    /// ranked hands under high rules.
+    pub fn explain_comparison(&self, other: &Self) -> ComparisonReason {
+        match self.category_index().cmp(&other.category_index()) {
+            Ordering::Greater => ComparisonReason::Category {
+                winner: self.category(),
+                loser: other.category(),
+            },
+            Ordering::Less => ComparisonReason::Category {
+                winner: other.category(),
+                loser: self.category(),
+            },
+            Ordering::Equal => {
+                for (left, right) in self
+                    .tiebreak_values()
+                    .into_iter()
+                    .zip(other.tiebreak_values())
+                {
+                    match left.cmp(&right) {
+                        Ordering::Equal => continue,
+                        Ordering::Greater => {
+                            return ComparisonReason::Kicker {
+                                category: self.category(),
+                                winner: left,
+                                loser: right,
+                            };
+                        }
+                        Ordering::Less => {
+                            return ComparisonReason::Kicker {
+                                category: self.category(),
+                                winner: right,
+                                loser: left,
+                            };
+                        }
+                    }
+                }
+                ComparisonReason::Tie
+            }
+        }
+    }
+}
 
-            (Self::ThreeOfAKind(_), _) => Ordering::Greater,
-            (_, Self::ThreeOfAKind(_)) => Ordering::Less,
+/// Why one ranked hand beat -- or tied with -- another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComparisonReason {
+    /// The winning hand sits in a higher-ranked category.
+    Category {
+        winner: &'static str,
+        loser: &'static str,
+    },
+    /// Both hands share a category; this card value decided it.
+    Kicker {
+        category: &'static str,
+        winner: Value,
+        loser: Value,
+    },
+    /// Nothing distinguishes the two hands.
+    Tie,
+}
 
-            (Self::TwoPair(_), _) => Ordering::Greater,
-            (_, Self::TwoPair(_)) => Ordering::Less,
+impl std::fmt::Display for ComparisonReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Category { winner, loser } => write!(f, "{} beats {}", winner, loser),
+            Self::Kicker {
+                category,
+                winner,
+                loser,
+            } => write!(f, "both {}, {} kicker beats {}", category, winner, loser),
+            Self::Tie => f.write_str("hands tie"),
+        }
+    }
+}
 
-            (Self::OnePair(_), _) => Ordering::Greater,
-            (_, Self::OnePair(_)) => Ordering::Less,
+/// The five card values of a qualifying low, highest first, or `None` if the
+/// hand does not make eight-or-better.
+fn eight_or_better_key(hand: &Hand) -> Option<Vec<Value>> {
+    let mut values = hand
+        .cards
+        .iter()
+        .map(|card| {
+            if card.value == Value::HighAce {
+                Value::LowAce
+            } else {
+                card.value
+            }
         })
+        .collect::<Vec<_>>();
+    values.sort();
+
+    if values.windows(2).all(|window| window[0] != window[1])
+        && values.last().copied() <= Some(Value::Eight)
+    {
+        values.reverse();
+        Some(values)
+    } else {
+        None
+    }
+}
+
+fn eight_or_better_cmp(lhs: &Hand, rhs: &Hand) -> Option<Ordering> {
+    match (eight_or_better_key(lhs), eight_or_better_key(rhs)) {
+        // Lexicographically smaller high-to-low values make the better low.
+        (Some(lhs), Some(rhs)) => Some(rhs.cmp(&lhs)),
+        (Some(_), None) => Some(Ordering::Greater),
+        (None, Some(_)) => Some(Ordering::Less),
+        (None, None) => None,
     }
 }
 
@@ -269,15 +481,18 @@ fn value_counts(hand: &Hand) -> HashMap<Value, usize> {
     value_map
 }
 
-fn count_values(hand: &Hand) -> HashMap<usize, Value> {
-    value_counts(hand)
-        .into_iter()
-        .map(|(key, value)| (value, key))
-        .collect()
-}
+type Check = fn(Hand) -> Option<HandRank>;
 
 fn straight_flush(hand: Hand) -> Option<HandRank> {
-    if straight(hand.clone()).is_some() && flush(hand.clone()).is_some() {
+    straight_flush_with(hand, straight)
+}
+
+fn straight_flush_ace_high_only(hand: Hand) -> Option<HandRank> {
+    straight_flush_with(hand, straight_ace_high_only)
+}
+
+fn straight_flush_with(hand: Hand, straight_check: Check) -> Option<HandRank> {
+    if straight_check(hand.clone()).is_some() && flush(hand.clone()).is_some() {
         Some(HandRank::StraightFlush(hand))
     } else {
         None
@@ -355,6 +570,17 @@ fn straight(hand: Hand) -> Option<HandRank> {
     }
 }
 
+fn straight_ace_high_only(hand: Hand) -> Option<HandRank> {
+    let mut card_values = hand.cards.iter().map(|card| card.value).collect::<Vec<_>>();
+    card_values.sort();
+
+    if is_straight(&card_values) {
+        Some(HandRank::Straight(hand))
+    } else {
+        None
+    }
+}
+
 fn three_of_a_kind(hand: Hand) -> Option<HandRank> {
     if value_counts(&hand).values().any(|&count| count == 3) {
         Some(HandRank::ThreeOfAKind(hand))
@@ -399,7 +625,337 @@ impl FromStr for Hand {
     }
 }
 
+/// A lending iterator over all `k`-card subsets of a slice of cards, in
+/// lexicographic index order. The current combination is stored in a buffer
+/// allocated once at construction, so stepping never allocates.
+pub struct Combinations<'a> {
+    cards: &'a [Card],
+    indices: Vec<usize>,
+    current: Vec<Card>,
+    started: bool,
+    done: bool,
+}
+
+impl Combinations<'_> {
+    /// Advance to the next combination, returning it as a slice, or `None`
+    /// once all combinations have been produced.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[Card]> {
+        let k = self.indices.len();
+        let n = self.cards.len();
+
+        if self.done {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+        } else {
+            // Find the rightmost index that can still move, then reset
+            // everything after it.
+            match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+                Some(pivot) => {
+                    self.indices[pivot] += 1;
+                    for i in pivot + 1..k {
+                        self.indices[i] = self.indices[i - 1] + 1;
+                    }
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+
+        let cards = self.cards;
+        self.current.clear();
+        self.current
+            .extend(self.indices.iter().map(|&index| cards[index]));
+        Some(&self.current)
+    }
+}
+
+/// Iterate over every `k`-card combination of `cards`.
+///
+/// Yields nothing when `k` exceeds the number of cards, and a single empty
+/// combination when `k` is zero.
+pub fn combinations(cards: &[Card], k: usize) -> Combinations<'_> {
+    Combinations {
+        cards,
+        indices: (0..k).collect(),
+        current: Vec::with_capacity(k),
+        started: false,
+        done: k > cards.len(),
+    }
+}
+
+/// How many cards improve a hand to a better category, and the chance of
+/// hitting one on the next street.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Improvement {
+    /// The category the hand improves to.
+    pub category: &'static str,
+    /// How many unseen cards make that category.
+    pub outs: usize,
+    /// The probability of drawing one of those outs next street.
+    pub probability: f64,
+}
+
+/// The result of counting outs for a hole-card/board combination.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outs {
+    /// How many unseen cards remain in the deck.
+    pub remaining: usize,
+    /// Reachable better categories, weakest first.
+    pub improvements: Vec<Improvement>,
+}
+
+fn full_deck() -> Vec<Card> {
+    let values = [
+        Value::Two,
+        Value::Three,
+        Value::Four,
+        Value::Five,
+        Value::Six,
+        Value::Seven,
+        Value::Eight,
+        Value::Nine,
+        Value::Ten,
+        Value::Jack,
+        Value::Queen,
+        Value::King,
+        Value::HighAce,
+    ];
+    let suits = [Suit::Spades, Suit::Clubs, Suit::Diamonds, Suit::Hearts];
+    values
+        .iter()
+        .flat_map(|&value| suits.iter().map(move |&suit| Card { value, suit }))
+        .collect()
+}
+
+fn parse_cards(cards: &str) -> Vec<Card> {
+    cards
+        .split_whitespace()
+        .map(|card| card.parse().unwrap())
+        .collect()
+}
+
+/// The best category reachable by any 5-card subset of `cards`.
+fn best_category(cards: &[Card]) -> (usize, &'static str) {
+    let mut combos = combinations(cards, 5);
+    let mut best = (0, "high card");
+    while let Some(combo) = combos.next() {
+        let mut cards = combo.to_vec();
+        sort_cards(&mut cards);
+        let rank = Hand { cards }.rank();
+        let candidate = (rank.category_index(), rank.category());
+        if candidate.0 > best.0 {
+            best = candidate;
+        }
+    }
+    best
+}
+
+/// Count the outs from two hole cards and a three- or four-card board to
+/// every better hand category, excluding any known dead cards from the
+/// deck. Probabilities are for the next street only.
+pub fn outs(hole: &str, board: &str, dead: &str) -> Outs {
+    let hole = parse_cards(hole);
+    let board = parse_cards(board);
+    let dead = parse_cards(dead);
+    assert_eq!(hole.len(), 2, "expected exactly two hole cards");
+    assert!(
+        (3..=4).contains(&board.len()),
+        "expected a flop or turn board"
+    );
+
+    let seen = hole
+        .iter()
+        .chain(board.iter())
+        .chain(dead.iter())
+        .copied()
+        .collect::<HashSet<_>>();
+    let known = hole.iter().chain(board.iter()).copied().collect::<Vec<_>>();
+    let (current, _) = best_category(&known);
+
+    let mut with_candidate = known.clone();
+    with_candidate.push(known[0]);
+
+    let mut outs_by_category = HashMap::new();
+    let remaining = 52 - seen.len();
+    for candidate in full_deck() {
+        if seen.contains(&candidate) {
+            continue;
+        }
+        *with_candidate.last_mut().unwrap() = candidate;
+        let (category_index, category) = best_category(&with_candidate);
+        if category_index > current {
+            *outs_by_category
+                .entry((category_index, category))
+                .or_insert(0) += 1;
+        }
+    }
+
+    let mut improvements = outs_by_category.into_iter().collect::<Vec<_>>();
+    improvements.sort_by_key(|&((index, _), _)| index);
+    Outs {
+        remaining,
+        improvements: improvements
+            .into_iter()
+            .map(|((_, category), outs)| Improvement {
+                category,
+                outs,
+                probability: outs as f64 / remaining as f64,
+            })
+            .collect(),
+    }
+}
+
+/// Errors reported by the fallible showdown API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `showdown` was called with no hands.
+    NoHands,
+    /// The same card appears in more than one hand.
+    DuplicateCard {
+        /// The duplicated card.
+        card: Card,
+        /// The indices of every hand containing it.
+        hands: Vec<usize>,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoHands => f.write_str("no hands were given"),
+            Self::DuplicateCard { card, hands } => {
+                write!(f, "card {} appears in hands", card)?;
+                for (i, hand) in hands.iter().enumerate() {
+                    write!(f, "{} {}", if i == 0 { "" } else { " and" }, hand)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// One hand's result in a [`Showdown`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowdownEntry {
+    /// The hand's index in the input slice.
+    pub index: usize,
+    /// The hand's evaluated rank.
+    pub rank: HandRank,
+    /// Finishing position, zero-based; tied hands share a position.
+    pub position: usize,
+}
+
+/// The outcome of a multi-way showdown: every hand's rank and finishing
+/// position, with split pots represented as shared positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Showdown {
+    entries: Vec<ShowdownEntry>,
+}
+
+impl Showdown {
+    /// Every hand's result, in input order.
+    pub fn entries(&self) -> &[ShowdownEntry] {
+        &self.entries
+    }
+
+    /// The indices of the hands sharing first position.
+    pub fn winning_indices(&self) -> Vec<usize> {
+        self.group(0)
+    }
+
+    /// The indices of the hands finishing in `position`.
+    pub fn group(&self, position: usize) -> Vec<usize> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.position == position)
+            .map(|entry| entry.index)
+            .collect()
+    }
+
+    /// Hand indices grouped by finishing position, best first. Hands in the
+    /// same group split the pot for that position.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let positions = self
+            .entries
+            .iter()
+            .map(|entry| entry.position)
+            .max()
+            .map_or(0, |max| max + 1);
+        (0..positions)
+            .map(|position| self.group(position))
+            .collect()
+    }
+}
+
+/// Evaluate a multi-way showdown under high rules.
+pub fn showdown(hands: &[&str]) -> Result<Showdown, Error> {
+    if hands.is_empty() {
+        return Err(Error::NoHands);
+    }
+
+    let mut card_owners: HashMap<Card, Vec<usize>> = HashMap::new();
+    for (index, hand) in hands.iter().enumerate() {
+        for card in parse_cards(hand) {
+            let owners = card_owners.entry(card).or_default();
+            if !owners.contains(&index) {
+                owners.push(index);
+            }
+        }
+    }
+    if let Some((&card, owners)) = card_owners
+        .iter()
+        .filter(|(_, owners)| owners.len() > 1)
+        .min_by_key(|(card, _)| (card.value, card.suit as usize))
+    {
+        return Err(Error::DuplicateCard {
+            card,
+            hands: owners.clone(),
+        });
+    }
+
+    let ranks = hands
+        .iter()
+        .map(|hand| hand.parse::<Hand>().unwrap().rank())
+        .collect::<Vec<_>>();
+
+    let mut order = (0..hands.len()).collect::<Vec<_>>();
+    order.sort_by(|&left, &right| ranks[right].cmp(&ranks[left]));
+
+    let mut positions = vec![0; hands.len()];
+    let mut position = 0;
+    for pair in order.windows(2) {
+        if ranks[pair[1]] < ranks[pair[0]] {
+            position += 1;
+        }
+        positions[pair[1]] = position;
+    }
+
+    Ok(Showdown {
+        entries: ranks
+            .into_iter()
+            .enumerate()
+            .map(|(index, rank)| ShowdownEntry {
+                index,
+                rank,
+                position: positions[index],
+            })
+            .collect(),
+    })
+}
+
 pub fn winning_hands<'a>(hands: &[&'a str]) -> Option<Vec<&'a str>> {
+    winning_hands_with(hands, RankingRules::High)
+}
+
+pub fn winning_hands_with<'a>(hands: &[&'a str], rules: RankingRules) -> Option<Vec<&'a str>> {
     let mut order = (0..hands.len()).collect::<Vec<_>>();
     let parsed_hands = hands
         .iter()
@@ -409,19 +965,19 @@ pub fn winning_hands<'a>(hands: &[&'a str]) -> Option<Vec<&'a str>> {
     order.sort_by(|&left, &right| {
         parsed_hands[right]
             .clone()
-            .rank()
-            .partial_cmp(&parsed_hands[left].clone().rank())
+            .rank_with(rules)
+            .compare(&parsed_hands[left].clone().rank_with(rules), rules)
             .unwrap_or(Ordering::Equal)
     });
 
     let best_index = order[0];
-    let best = parsed_hands[best_index].clone().rank();
+    let best = parsed_hands[best_index].clone().rank_with(rules);
 
     Some(
         order
             .iter()
             .filter(|&&index| {
-                best.partial_cmp(&parsed_hands[index].clone().rank())
+                best.compare(&parsed_hands[index].clone().rank_with(rules), rules)
                     .unwrap_or(Ordering::Equal)
                     == Ordering::Equal
             })
@@ -0,0 +1,71 @@
+//! Plain-text rendering of hands and community boards for CLI front-ends.
+
+use crate::{parse_cards, Card, Suit};
+
+/// How suits are drawn in rendered output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Style {
+    /// Letter suits, matching the parser's canonical form: `10H JD`.
+    Ascii,
+    /// Unicode suit symbols: `10\u{2665} J\u{2666}`.
+    Unicode,
+}
+
+fn suit_symbol(suit: Suit) -> &'static str {
+    match suit {
+        Suit::Spades => "\u{2660}",
+        Suit::Clubs => "\u{2663}",
+        Suit::Diamonds => "\u{2666}",
+        Suit::Hearts => "\u{2665}",
+    }
+}
+
+fn card_text(card: &Card, style: Style) -> String {
+    match style {
+        Style::Ascii => card.to_string(),
+        Style::Unicode => format!("{}{}", card.value, suit_symbol(card.suit)),
+    }
+}
+
+/// Render labelled hands and an optional community board as an aligned
+/// table, one row per hand with the board last. Cards are right-aligned so
+/// ten-valued cards line up with the rest.
+pub fn render_table(hands: &[(&str, &str)], board: Option<&str>, style: Style) -> String {
+    let mut rows = hands
+        .iter()
+        .map(|&(label, hand)| (label, parse_cards(hand)))
+        .collect::<Vec<_>>();
+    if let Some(board) = board {
+        rows.push(("board", parse_cards(board)));
+    }
+
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let card_width = rows
+        .iter()
+        .flat_map(|(_, cards)| cards.iter())
+        .map(|card| card_text(card, style).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut result = String::new();
+    for (label, cards) in rows {
+        result.push_str(label);
+        for _ in label.chars().count()..label_width {
+            result.push(' ');
+        }
+        for card in &cards {
+            let text = card_text(card, style);
+            result.push(' ');
+            for _ in text.chars().count()..card_width {
+                result.push(' ');
+            }
+            result.push_str(&text);
+        }
+        result.push('\n');
+    }
+    result
+}
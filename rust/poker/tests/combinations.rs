@@ -0,0 +1,47 @@
+use poker::{combinations, Card};
+
+fn cards(input: &str) -> Vec<Card> {
+    input.split(' ').map(|card| card.parse().unwrap()).collect()
+}
+
+fn collect_combinations(input: &str, k: usize) -> Vec<Vec<Card>> {
+    let cards = cards(input);
+    let mut combos = combinations(&cards, k);
+    let mut result = Vec::new();
+    while let Some(combo) = combos.next() {
+        result.push(combo.to_vec());
+    }
+    result
+}
+
+#[test]
+fn test_five_choose_two_yields_ten() {
+    assert_eq!(collect_combinations("2S 3D 4H 5C 6S", 2).len(), 10);
+}
+
+#[test]
+fn test_seven_choose_five_yields_twenty_one() {
+    let combos = collect_combinations("2S 3D 4H 5C 6S 7D 8H", 5);
+    assert_eq!(combos.len(), 21);
+    assert!(combos.iter().all(|combo| combo.len() == 5));
+}
+
+#[test]
+fn test_combinations_are_distinct_and_ordered() {
+    let combos = collect_combinations("2S 3D 4H 5C 6S", 3);
+    for window in combos.windows(2) {
+        assert_ne!(window[0], window[1]);
+    }
+    assert_eq!(combos.first().unwrap(), &cards("2S 3D 4H"));
+    assert_eq!(combos.last().unwrap(), &cards("4H 5C 6S"));
+}
+
+#[test]
+fn test_k_zero_yields_single_empty_combination() {
+    assert_eq!(collect_combinations("2S 3D", 0), vec![Vec::new()]);
+}
+
+#[test]
+fn test_k_larger_than_input_yields_nothing() {
+    assert!(collect_combinations("2S 3D", 3).is_empty());
+}
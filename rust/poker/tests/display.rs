@@ -0,0 +1,28 @@
+use poker::{Card, Hand};
+
+#[test]
+fn test_card_display_is_canonical() {
+    assert_eq!("10H".parse::<Card>().unwrap().to_string(), "10H");
+    assert_eq!("AS".parse::<Card>().unwrap().to_string(), "AS");
+}
+
+#[test]
+fn test_ten_shorthand_parses() {
+    assert_eq!("TH".parse::<Card>().unwrap(), "10H".parse().unwrap());
+}
+
+#[test]
+fn test_unicode_suits_parse() {
+    assert_eq!("A\u{2660}".parse::<Card>().unwrap(), "AS".parse().unwrap());
+    assert_eq!("K\u{2665}".parse::<Card>().unwrap(), "KH".parse().unwrap());
+    assert_eq!("Q\u{2666}".parse::<Card>().unwrap(), "QD".parse().unwrap());
+    assert_eq!("J\u{2663}".parse::<Card>().unwrap(), "JC".parse().unwrap());
+}
+
+#[test]
+fn test_hand_round_trips_through_display() {
+    let hand = "T\u{2665} J\u{2666} 3S 5C 2D".parse::<Hand>().unwrap();
+    let formatted = hand.to_string();
+    assert_eq!(formatted, "JD 10H 5C 3S 2D");
+    assert_eq!(formatted.parse::<Hand>().unwrap(), hand);
+}
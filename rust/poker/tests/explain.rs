@@ -0,0 +1,37 @@
+use poker::{ComparisonReason, Hand};
+
+fn rank(hand: &str) -> poker::HandRank {
+    hand.parse::<Hand>().unwrap().rank()
+}
+
+#[test]
+fn test_different_categories() {
+    let reason = rank("2S 3S 9S JS KS").explain_comparison(&rank("4D 5S 6S 7D 8C"));
+    assert_eq!(
+        reason,
+        ComparisonReason::Category {
+            winner: "flush",
+            loser: "straight",
+        }
+    );
+    assert_eq!(reason.to_string(), "flush beats straight");
+}
+
+#[test]
+fn test_flush_kicker_decides() {
+    let reason = rank("2S 3S 9S JS AS").explain_comparison(&rank("2D 3D 9D JD KD"));
+    assert_eq!(reason.to_string(), "both flush, A kicker beats K");
+}
+
+#[test]
+fn test_pair_rank_decides_before_kickers() {
+    // The pair of threes wins even though the twos carry an ace kicker.
+    let reason = rank("3S 3D 4H 5C 6S").explain_comparison(&rank("2S 2D AH KC QS"));
+    assert_eq!(reason.to_string(), "both pair, 3 kicker beats 2");
+}
+
+#[test]
+fn test_identical_ranks_tie() {
+    let reason = rank("2S 3H 9C JD KS").explain_comparison(&rank("2D 3C 9H JS KD"));
+    assert_eq!(reason, ComparisonReason::Tie);
+}
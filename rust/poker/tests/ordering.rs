@@ -0,0 +1,41 @@
+use poker::{Hand, HandRank};
+use std::cmp::Ordering;
+
+fn rank(hand: &str) -> HandRank {
+    hand.parse::<Hand>().unwrap().rank()
+}
+
+#[test]
+fn test_equal_ranks_compare_equal() {
+    let left = rank("2S 3H 9C JD KS");
+    let right = rank("2D 3C 9H JS KD");
+    assert_eq!(left.cmp(&right), Ordering::Equal);
+    assert_eq!(left, right, "equality agrees with `Ord`");
+}
+
+#[test]
+fn test_pair_rank_dominates_kickers() {
+    // A pair of threes beats a pair of twos regardless of the kickers.
+    assert!(rank("3S 3D 4H 5C 6S") > rank("2S 2D AH KC QS"));
+}
+
+#[test]
+fn test_sorting_hand_ranks_is_stable_and_total() {
+    let mut ranks = [
+        rank("4D 5S 6S 8D 3C"),
+        rank("2S 4C 7S 9H 10H"),
+        rank("2S 8H AS QS 3S"),
+        rank("4H 7H 8H 9H 6H"),
+    ];
+    ranks.sort();
+    assert_eq!(
+        ranks.last().unwrap().clone(),
+        rank("4H 7H 8H 9H 6H"),
+        "the straight flush sorts last"
+    );
+}
+
+#[test]
+fn test_wheel_straight_sorts_below_six_high_straight() {
+    assert!(rank("AS 2D 3H 4C 5S") < rank("2S 3D 4H 5C 6S"));
+}
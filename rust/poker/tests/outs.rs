@@ -0,0 +1,50 @@
+use poker::outs;
+
+#[test]
+fn test_flush_draw_has_nine_outs() {
+    let result = outs("AS KS", "2S 7S 9D", "");
+    assert_eq!(result.remaining, 47);
+    let flush = result
+        .improvements
+        .iter()
+        .find(|improvement| improvement.category == "flush")
+        .expect("a flush must be reachable");
+    assert_eq!(flush.outs, 9);
+    assert!((flush.probability - 9.0 / 47.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_pairing_outs_exclude_flush_makers() {
+    let result = outs("AS KS", "2S 7S 9D", "");
+    let pair = result
+        .improvements
+        .iter()
+        .find(|improvement| improvement.category == "pair")
+        .expect("a pair must be reachable");
+    // Three cards pair each known value, except that the nine of spades
+    // makes a flush instead.
+    assert_eq!(pair.outs, 14);
+}
+
+#[test]
+fn test_dead_cards_shrink_the_deck_and_the_outs() {
+    let result = outs("AS KS", "2S 7S 9D", "QS JS");
+    assert_eq!(result.remaining, 45);
+    let flush = result
+        .improvements
+        .iter()
+        .find(|improvement| improvement.category == "flush")
+        .unwrap();
+    assert_eq!(flush.outs, 7);
+}
+
+#[test]
+fn test_made_hand_only_counts_better_categories() {
+    // A made flush: only full houses and better should appear, and with
+    // no pair on board there are no such outs.
+    let result = outs("AS KS", "2S 7S 9S", "");
+    assert!(result
+        .improvements
+        .iter()
+        .all(|improvement| improvement.category != "pair"));
+}
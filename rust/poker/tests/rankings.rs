@@ -0,0 +1,81 @@
+use poker::{winning_hands_with, RankingRules};
+use std::collections::HashSet;
+
+fn hs_from<'a>(input: &[&'a str]) -> HashSet<&'a str> {
+    input.iter().copied().collect()
+}
+
+fn test(rules: RankingRules, input: &[&str], expected: &[&str]) {
+    assert_eq!(
+        hs_from(&winning_hands_with(input, rules).expect("This test should produce Some value")),
+        hs_from(expected)
+    )
+}
+
+#[test]
+fn test_high_rules_match_winning_hands() {
+    test(
+        RankingRules::High,
+        &["4D 5S 6S 8D 3C", "2S 4C 7S 9H 10H", "3S 4S 5D 6H JH"],
+        &["3S 4S 5D 6H JH"],
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_worst_high_hand_wins() {
+    test(
+        RankingRules::DeuceToSevenLow,
+        &["2S 3D 4H 5C 7S", "2S 3D 4H 5C 8S"],
+        &["2S 3D 4H 5C 7S"],
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_straights_count_against() {
+    // 2-3-4-5-6 is a straight and loses to any unmade hand.
+    test(
+        RankingRules::DeuceToSevenLow,
+        &["2S 3D 4H 5C 6S", "2S 3D 4H 5C 9S"],
+        &["2S 3D 4H 5C 9S"],
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_wheel_is_not_a_straight() {
+    // A-2-3-4-5 is merely ace-high in deuce-to-seven, so it beats a pair.
+    test(
+        RankingRules::DeuceToSevenLow,
+        &["AS 2D 3H 4C 5S", "2S 2D 4H 5C 7S"],
+        &["AS 2D 3H 4C 5S"],
+    )
+}
+
+#[test]
+fn test_deuce_to_seven_suited_wheel_is_only_a_flush() {
+    // A suited wheel is no straight flush in deuce-to-seven: both hands
+    // are flushes, and A-5-4-3-2 is the lower one.
+    test(
+        RankingRules::DeuceToSevenLow,
+        &["AS 2S 3S 4S 5S", "AH KH QH JH 9H"],
+        &["AS 2S 3S 4S 5S"],
+    )
+}
+
+#[test]
+fn test_eight_or_better_lower_low_wins() {
+    test(
+        RankingRules::EightOrBetterLow,
+        &["AS 2D 3H 4C 6S", "AS 2D 3H 4C 7S"],
+        &["AS 2D 3H 4C 6S"],
+    )
+}
+
+#[test]
+fn test_eight_or_better_qualifier_beats_non_qualifier() {
+    // A pair never qualifies for the low.
+    test(
+        RankingRules::EightOrBetterLow,
+        &["8S 7D 4H 2C AS", "AS AD 2H 3C 4S"],
+        &["8S 7D 4H 2C AS"],
+    )
+}
@@ -0,0 +1,28 @@
+use poker::render::{render_table, Style};
+
+#[test]
+fn test_ascii_table_aligns_labels_and_cards() {
+    let table = render_table(
+        &[("Alice", "10H JD 3S 5C 2D"), ("Bob", "AS KD QH JC 9S")],
+        Some("2C 7C 8H"),
+        Style::Ascii,
+    );
+    assert_eq!(
+        table,
+        "Alice 10H  JD  3S  5C  2D\n\
+         Bob    AS  KD  QH  JC  9S\n\
+         board  2C  7C  8H\n"
+    );
+}
+
+#[test]
+fn test_unicode_style_uses_suit_symbols() {
+    let table = render_table(&[("hero", "AS KH")], None, Style::Unicode);
+    assert_eq!(table, "hero A\u{2660} K\u{2665}\n");
+}
+
+#[test]
+fn test_no_board_renders_only_hands() {
+    let table = render_table(&[("solo", "2S 3D")], None, Style::Ascii);
+    assert_eq!(table, "solo 2S 3D\n");
+}
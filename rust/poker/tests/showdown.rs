@@ -0,0 +1,45 @@
+use poker::{showdown, Error};
+
+#[test]
+fn test_no_hands_is_an_error() {
+    assert_eq!(showdown(&[]).unwrap_err(), Error::NoHands);
+}
+
+#[test]
+fn test_positions_cover_all_hands() {
+    let result = showdown(&["4D 5S 6S 8D 3C", "2S 4C 7S 9H 10H", "3S 4S 5D 6H JH"]).unwrap();
+    let positions = result
+        .entries()
+        .iter()
+        .map(|entry| entry.position)
+        .collect::<Vec<_>>();
+    assert_eq!(positions, [2, 1, 0]);
+    assert_eq!(result.winning_indices(), [2]);
+}
+
+#[test]
+fn test_split_pot_groups_share_a_position() {
+    let result = showdown(&["2S 3H 9C JD KS", "4D 5S 6S 8D 2C", "2D 3C 9H JS KD"]).unwrap();
+    assert_eq!(result.winning_indices(), [0, 2]);
+    assert_eq!(result.groups(), [vec![0, 2], vec![1]]);
+}
+
+#[test]
+fn test_duplicate_cards_across_hands_are_rejected() {
+    let error = showdown(&["AS 2S 3H 4C 5D", "AS KD QH JC 9S"]).unwrap_err();
+    match &error {
+        Error::DuplicateCard { card, hands } => {
+            assert_eq!(card.to_string(), "AS");
+            assert_eq!(hands, &[0, 1]);
+        }
+        other => panic!("expected a duplicate card error, got {:?}", other),
+    }
+    assert_eq!(error.to_string(), "card AS appears in hands 0 and 1");
+}
+
+#[test]
+fn test_duplicates_within_a_single_hand_are_allowed_through_this_check() {
+    // Cross-hand validation only; a doctored single hand is the caller's
+    // problem, as it always has been.
+    assert!(showdown(&["AS AS 3H 4C 5D"]).is_ok());
+}
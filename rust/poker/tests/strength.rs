@@ -0,0 +1,40 @@
+use poker::{Hand, HandRank};
+
+fn rank(hand: &str) -> HandRank {
+    hand.parse::<Hand>().unwrap().rank()
+}
+
+#[test]
+fn test_strength_is_monotone_across_categories() {
+    let ladder = [
+        "2S 3H 9C JD KS", // high card
+        "2S 2D AH KC QS", // pair
+        "2S 2D 3H 3C QS", // two pair
+        "4S 4D 4H 9C JD", // three of a kind
+        "4D 5S 6S 7D 8C", // straight
+        "2S 3S 9S JS KS", // flush
+        "4S 4D 4H 9C 9D", // full house
+        "4S 4D 4H 4C 9D", // four of a kind
+        "4H 5H 6H 7H 8H", // straight flush
+    ];
+    let strengths = ladder.iter().map(|hand| rank(hand).strength());
+    let mut previous = -1.0;
+    for strength in strengths {
+        assert!(strength > previous);
+        assert!((0.0..1.0).contains(&strength));
+        previous = strength;
+    }
+}
+
+#[test]
+fn test_kickers_move_the_score() {
+    assert!(rank("2S 3S 9S JS AS").strength() > rank("2D 3D 9D JD KD").strength());
+}
+
+#[test]
+fn test_equal_ranks_score_equally() {
+    assert_eq!(
+        rank("2S 3H 9C JD KS").strength(),
+        rank("2D 3C 9H JS KD").strength()
+    );
+}
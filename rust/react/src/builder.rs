@@ -0,0 +1,119 @@
+//! A declarative way to define many cells at once.
+//!
+//! Cells are declared by name and wired up by name, so large graphs don't
+//! need IDs threaded through them by hand; `build` returns the reactor
+//! together with a name→ID map for the cells you still want to poke at.
+
+use crate::{CellID, ComputeError, CreateComputeError, Reactor};
+use std::collections::HashMap;
+
+type BoxedComputeFunc<'a, T> = Box<dyn Fn(&[T]) -> Result<T, ComputeError> + 'a>;
+
+pub struct ReactorBuilder<'a, T> {
+    definitions: Vec<Definition<'a, T>>,
+}
+
+enum Definition<'a, T> {
+    Input {
+        name: String,
+        initial: T,
+    },
+    Compute {
+        name: String,
+        dependencies: Vec<String>,
+        func: BoxedComputeFunc<'a, T>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BuildError {
+    /// Two cells were declared with the same name.
+    DuplicateName(String),
+    /// A compute cell depends on a name that no earlier declaration
+    /// defines. Carries the dependency name.
+    UnknownCell(String),
+    /// The underlying reactor rejected a cell (e.g. a cycle).
+    CreateCompute(CreateComputeError),
+}
+
+impl<'a, T> ReactorBuilder<'a, T>
+where
+    T: Clone + PartialEq + 'a,
+{
+    pub fn new() -> Self {
+        Self {
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Declare an input cell.
+    pub fn input(mut self, name: &str, initial: T) -> Self {
+        self.definitions.push(Definition::Input {
+            name: name.to_string(),
+            initial,
+        });
+        self
+    }
+
+    /// Declare a compute cell. Dependencies are named, and must be declared
+    /// before the cells that use them.
+    pub fn compute<F>(self, name: &str, dependencies: &[&str], compute_func: F) -> Self
+    where
+        F: Fn(&[T]) -> T + 'a,
+    {
+        self.compute_fallible(name, dependencies, move |values| Ok(compute_func(values)))
+    }
+
+    /// Like [`ReactorBuilder::compute`], but the function can fail.
+    pub fn compute_fallible<F>(mut self, name: &str, dependencies: &[&str], compute_func: F) -> Self
+    where
+        F: Fn(&[T]) -> Result<T, ComputeError> + 'a,
+    {
+        self.definitions.push(Definition::Compute {
+            name: name.to_string(),
+            dependencies: dependencies.iter().map(|dep| dep.to_string()).collect(),
+            func: Box::new(compute_func),
+        });
+        self
+    }
+
+    /// Create the reactor and every declared cell, in declaration order.
+    pub fn build(self) -> Result<(Reactor<'a, T>, HashMap<String, CellID>), BuildError> {
+        let mut reactor = Reactor::new();
+        let mut names = HashMap::new();
+        for definition in self.definitions {
+            let (name, cell) = match definition {
+                Definition::Input { name, initial } => {
+                    (name, CellID::Input(reactor.create_input(initial)))
+                }
+                Definition::Compute {
+                    name,
+                    dependencies,
+                    func,
+                } => {
+                    let dependencies = dependencies
+                        .into_iter()
+                        .map(|dep| names.get(&dep).copied().ok_or(BuildError::UnknownCell(dep)))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let cell = reactor
+                        .create_compute_fallible(&dependencies, func)
+                        .map_err(BuildError::CreateCompute)?;
+                    (name, CellID::Compute(cell))
+                }
+            };
+            if names.insert(name.clone(), cell).is_some() {
+                return Err(BuildError::DuplicateName(name));
+            }
+        }
+        Ok((reactor, names))
+    }
+}
+
+impl<'a, T> Default for ReactorBuilder<'a, T>
+where
+    T: Clone + PartialEq + 'a,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,27 +1,32 @@
+pub mod builder;
+pub mod sync;
+
 use lazy_static::lazy_static;
 use std::{
-    collections::{HashMap, HashSet},
-    sync::atomic::{AtomicUsize, Ordering},
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet, VecDeque},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
 };
 
 lazy_static! {
-    static ref OBJECT_ID: AtomicUsize = AtomicUsize::new(0);
+    // One global counter hands out reactor identities; everything else is
+    // allocated per reactor, so cell IDs stay small and IDs minted by one
+    // reactor are deterministically rejected by every other.
+    static ref REACTOR_ID: AtomicUsize = AtomicUsize::new(0);
 }
 
 /// `InputCellID` is a unique identifier for an input cell.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputCellID {
+    reactor: usize,
     id: usize,
 }
 
-impl InputCellID {
-    fn new() -> Self {
-        Self {
-            id: OBJECT_ID.fetch_add(1, Ordering::SeqCst),
-        }
-    }
-}
-
 /// `ComputeCellID` is a unique identifier for a compute cell.
 /// Values of type `InputCellID` and `ComputeCellID` should not be mutually assignable,
 /// demonstrated by the following tests:
@@ -37,31 +42,18 @@ impl InputCellID {
 /// let compute: react::InputCellID = r.create_compute(&[react::CellID::Input(input)], |_| 222).unwrap();
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComputeCellID {
+    reactor: usize,
     id: usize,
 }
 
-impl ComputeCellID {
-    fn new() -> Self {
-        Self {
-            id: OBJECT_ID.fetch_add(1, Ordering::SeqCst),
-        }
-    }
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct CallbackID {
+    reactor: usize,
     id: usize,
 }
 
-impl CallbackID {
-    fn new() -> Self {
-        Self {
-            id: OBJECT_ID.fetch_add(1, Ordering::SeqCst),
-        }
-    }
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CellID {
     Input(InputCellID),
@@ -74,36 +66,137 @@ pub enum RemoveCallbackError {
     NonexistentCallback,
 }
 
+/// Errors returned by `Reactor::create_compute`.
+#[derive(Debug, PartialEq)]
+pub enum CreateComputeError {
+    /// A dependency does not exist; carries the offending ID.
+    NonexistentDependency(CellID),
+    /// The new cell would participate in a dependency cycle, which would
+    /// recurse forever when evaluated.
+    Cycle,
+}
+
+/// The error state carried by a failed compute cell. A concrete type keeps
+/// `Reactor::new()` inferable; callers embed whatever they need in the
+/// message.
+pub type ComputeError = String;
+
+type ComputeFunc<'a, T> = Box<dyn Fn(&[T]) -> Result<T, ComputeError> + 'a>;
+type TrackedFunc<'a, T> = Box<dyn for<'r> Fn(&TrackingContext<'r, 'a, T>) -> T + 'a>;
+type Callbacks<'a, T> = HashMap<CallbackID, Box<dyn FnMut(T) + 'a>>;
+type ErrorCallbacks<'a> = HashMap<CallbackID, Box<dyn FnMut(ComputeError) + 'a>>;
+type FullCallbacks<'a, T> = HashMap<CallbackID, Box<dyn FnMut(ComputeCellID, T, T) + 'a>>;
+type FoldFunc<'a, T> = Box<dyn Fn(&T, &[T]) -> T + 'a>;
+type EqFunc<'a, T> = Box<dyn Fn(&T, &T) -> bool + 'a>;
+type PendingChanges<T> = Vec<(
+    ComputeCellID,
+    Option<Result<T, ComputeError>>,
+    Result<T, ComputeError>,
+)>;
+
+/// When compute cells are recomputed. See [`Reactor::set_evaluation_policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvaluationPolicy {
+    /// Recompute dirty cells inside `set_value` and fire callbacks
+    /// immediately. The default.
+    Eager,
+    /// Only mark cells dirty on `set_value`: values are brought up to date
+    /// by the next read and callbacks wait for [`Reactor::flush`].
+    /// Back-to-back input changes coalesce into a single recomputation.
+    Lazy,
+}
+
+/// A point-in-time copy of a reactor's state: every input value plus the
+/// cached value (or error) of every compute cell. Compute *functions* are
+/// not captured -- they are re-bound by ID when the snapshot is restored
+/// into a reactor that created the same cells, which is what makes the
+/// optional `serde` feature useful for persisting state across runs.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReactorSnapshot<T> {
+    inputs: Vec<(InputCellID, T)>,
+    computes: Vec<(ComputeCellID, Result<T, ComputeError>)>,
+}
+
+// Input changes kept for undo before the oldest entries are discarded.
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
 pub struct Reactor<'a, T> {
-    // Just so that the compiler doesn't complain about an unused type parameter.
-    // You probably want to delete this field.
+    id: usize,
+    next_object_id: usize,
     graph: HashMap<CellID, Vec<CellID>>,
     input_values: HashMap<InputCellID, T>,
-    compute_cell_funcs: HashMap<
-        ComputeCellID,
-        (
-            Box<dyn Fn(&[T]) -> T + 'a>,
-            HashMap<CallbackID, Box<dyn FnMut(T) + 'a>>,
-        ),
-    >,
+    // Caches mutated from `&self`, since reads settle pending work under
+    // the lazy policy. They are sync locks rather than `RefCell`s so that
+    // [`sync::SyncReactor`] can serve settled reads to several threads at
+    // once.
+    compute_values: RwLock<HashMap<ComputeCellID, Result<T, ComputeError>>>,
+    compute_cell_funcs: HashMap<ComputeCellID, ComputeFunc<'a, T>>,
+    tracked_funcs: HashMap<ComputeCellID, TrackedFunc<'a, T>>,
+    fold_funcs: HashMap<ComputeCellID, FoldFunc<'a, T>>,
+    eq_funcs: HashMap<ComputeCellID, EqFunc<'a, T>>,
+    value_callbacks: HashMap<ComputeCellID, Callbacks<'a, T>>,
+    error_callbacks: HashMap<ComputeCellID, ErrorCallbacks<'a>>,
+    full_callbacks: HashMap<ComputeCellID, FullCallbacks<'a, T>>,
+    subscription_flags: Vec<(ComputeCellID, CallbackID, Rc<Cell<bool>>)>,
+    dirty: RwLock<HashSet<ComputeCellID>>,
+    pending_changes: RwLock<PendingChanges<T>>,
+    pending_tracked_edges: RwLock<Vec<(ComputeCellID, Vec<CellID>)>>,
+    policy: EvaluationPolicy,
+    undo_stack: VecDeque<(InputCellID, T, T)>,
+    redo_stack: Vec<(InputCellID, T, T)>,
+    history_limit: usize,
 }
 
-// You are guaranteed that Reactor will only be tested against types that are Copy + PartialEq.
-impl<'a, T> Reactor<'a, T>
-where
-    T: Copy + PartialEq,
-{
-    pub fn new() -> Self {
+impl<'a, T> Default for Reactor<'a, T> {
+    fn default() -> Self {
         Self {
+            id: REACTOR_ID.fetch_add(1, Ordering::SeqCst),
+            next_object_id: 0,
             graph: Default::default(),
             input_values: Default::default(),
+            compute_values: Default::default(),
             compute_cell_funcs: Default::default(),
+            tracked_funcs: Default::default(),
+            fold_funcs: Default::default(),
+            eq_funcs: Default::default(),
+            value_callbacks: Default::default(),
+            error_callbacks: Default::default(),
+            full_callbacks: Default::default(),
+            subscription_flags: Default::default(),
+            dirty: Default::default(),
+            pending_changes: Default::default(),
+            pending_tracked_edges: Default::default(),
+            policy: EvaluationPolicy::Eager,
+            undo_stack: Default::default(),
+            redo_stack: Default::default(),
+            history_limit: DEFAULT_HISTORY_LIMIT,
         }
     }
+}
+
+// Cell values only need to be cloneable and comparable; non-Copy types like
+// String and Vec are fine.
+impl<'a, T> Reactor<'a, T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next_object_id(&mut self) -> usize {
+        let id = self.next_object_id;
+        self.next_object_id += 1;
+        id
+    }
 
     // Creates an input cell with the specified initial value, returning its ID.
     pub fn create_input(&mut self, initial: T) -> InputCellID {
-        let input_cell_id = InputCellID::new();
+        let input_cell_id = InputCellID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
         self.graph.entry(CellID::Input(input_cell_id)).or_default();
         self.input_values.insert(input_cell_id, initial);
         input_cell_id
@@ -126,28 +219,200 @@ where
         &mut self,
         dependencies: &[CellID],
         compute_func: F,
-    ) -> Result<ComputeCellID, CellID>
+    ) -> Result<ComputeCellID, CreateComputeError>
     where
         F: Fn(&[T]) -> T + 'a,
     {
+        self.create_compute_fallible(dependencies, move |values| Ok(compute_func(values)))
+    }
+
+    // Creates a compute cell whose function can fail. An `Err` puts the cell
+    // -- and every cell downstream of it -- into an error state that
+    // propagates through `value_result` and error callbacks; `value` returns
+    // `None` for erroring cells.
+    pub fn create_compute_fallible<F>(
+        &mut self,
+        dependencies: &[CellID],
+        compute_func: F,
+    ) -> Result<ComputeCellID, CreateComputeError>
+    where
+        F: Fn(&[T]) -> Result<T, ComputeError> + 'a,
+    {
+        let compute_cell_id = ComputeCellID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
+        let id = CellID::Compute(compute_cell_id);
+        for &dep in dependencies.iter() {
+            if dep != id && !self.graph.contains_key(&dep) {
+                return Err(CreateComputeError::NonexistentDependency(dep));
+            }
+        }
+
+        self.graph.insert(id, dependencies.to_vec());
+        if dependencies.iter().any(|&dep| self.depends_on(dep, id)) {
+            self.graph.remove(&id);
+            return Err(CreateComputeError::Cycle);
+        }
+
+        self.compute_cell_funcs
+            .insert(compute_cell_id, Box::new(compute_func));
+        self.settle();
+        let initial = self
+            .evaluate(compute_cell_id)
+            .expect("dependencies were just validated");
+        self.compute_values
+            .write()
+            .unwrap()
+            .insert(compute_cell_id, initial);
+        Ok(compute_cell_id)
+    }
+
+    // Creates a compute cell whose change detection uses a caller-supplied
+    // equality instead of `PartialEq` on the whole value -- e.g. a cheap
+    // version counter on a large value, or `|_, _| false` to notify on
+    // every recomputation. Only callback suppression is affected; `value`
+    // always returns the latest result.
+    pub fn create_compute_with_eq<F, E>(
+        &mut self,
+        dependencies: &[CellID],
+        compute_func: F,
+        eq_func: E,
+    ) -> Result<ComputeCellID, CreateComputeError>
+    where
+        F: Fn(&[T]) -> T + 'a,
+        E: Fn(&T, &T) -> bool + 'a,
+    {
+        let compute_cell_id = self.create_compute(dependencies, compute_func)?;
+        self.eq_funcs.insert(compute_cell_id, Box::new(eq_func));
+        Ok(compute_cell_id)
+    }
+
+    // Creates a compute cell that folds over its own previous value: on
+    // every dependency change the closure receives the cell's current value
+    // and the dependencies' new values, starting from `initial`. Useful for
+    // running totals and other accumulator-style cells. A dependency error
+    // is sticky, since the running value is lost when the cell errors.
+    pub fn create_fold<F>(
+        &mut self,
+        dependencies: &[CellID],
+        initial: T,
+        fold_func: F,
+    ) -> Result<ComputeCellID, CreateComputeError>
+    where
+        F: Fn(&T, &[T]) -> T + 'a,
+    {
+        let compute_cell_id = ComputeCellID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
         for &dep in dependencies.iter() {
             if !self.graph.contains_key(&dep) {
-                return Err(dep);
+                return Err(CreateComputeError::NonexistentDependency(dep));
             }
         }
 
-        let compute_cell_id = ComputeCellID::new();
-        self.compute_cell_funcs.insert(
-            compute_cell_id,
-            (Box::new(compute_func), Default::default()),
-        );
-        self.graph.insert(
-            CellID::Compute(compute_cell_id),
-            dependencies.iter().copied().collect(),
-        );
+        self.graph
+            .insert(CellID::Compute(compute_cell_id), dependencies.to_vec());
+        self.fold_funcs.insert(compute_cell_id, Box::new(fold_func));
+        self.compute_values
+            .write()
+            .unwrap()
+            .insert(compute_cell_id, Ok(initial));
         Ok(compute_cell_id)
     }
 
+    // Creates a compute cell whose dependencies are discovered by running
+    // it: the closure reads other cells through the context handle and the
+    // reactor records which cells were actually read, refreshing the
+    // cell's edges after every evaluation. Reads that would close a cycle
+    // still return the cached value but are not recorded as edges.
+    pub fn create_compute_tracked<F>(&mut self, compute_func: F) -> ComputeCellID
+    where
+        F: for<'r> Fn(&TrackingContext<'r, 'a, T>) -> T + 'a,
+    {
+        let compute_cell_id = ComputeCellID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
+        self.tracked_funcs
+            .insert(compute_cell_id, Box::new(compute_func));
+        self.graph.insert(CellID::Compute(compute_cell_id), vec![]);
+        self.settle();
+        let (value, reads) = self.run_tracked(compute_cell_id);
+        self.set_tracked_edges(compute_cell_id, reads);
+        self.compute_values
+            .write()
+            .unwrap()
+            .insert(compute_cell_id, Ok(value));
+        compute_cell_id
+    }
+
+    fn run_tracked(&self, id: ComputeCellID) -> (T, Vec<CellID>) {
+        let func = &self.tracked_funcs[&id];
+        let context = TrackingContext {
+            reactor: self,
+            reads: RefCell::new(Vec::new()),
+        };
+        let value = func(&context);
+        (value, context.reads.into_inner())
+    }
+
+    fn set_tracked_edges(&mut self, id: ComputeCellID, reads: Vec<CellID>) {
+        let cell = CellID::Compute(id);
+        let edges = reads
+            .into_iter()
+            .filter(|dep| self.graph.contains_key(dep) && !self.depends_on(*dep, cell))
+            .collect();
+        self.graph.insert(cell, edges);
+    }
+
+    fn is_compute_cell(&self, id: ComputeCellID) -> bool {
+        self.compute_cell_funcs.contains_key(&id)
+            || self.tracked_funcs.contains_key(&id)
+            || self.fold_funcs.contains_key(&id)
+    }
+
+    /// Apply a compute cell's function to its dependencies' cached values.
+    /// A dependency in an error state short-circuits into that same error.
+    fn evaluate(&self, id: ComputeCellID) -> Option<Result<T, ComputeError>> {
+        let mut evaluated_deps = vec![];
+        for &dep in self.graph.get(&CellID::Compute(id))?.iter() {
+            match self.cached_result(dep)? {
+                Ok(value) => evaluated_deps.push(value),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        if let Some(fold_func) = self.fold_funcs.get(&id) {
+            let previous = self.compute_values.read().unwrap().get(&id)?.clone();
+            return match previous {
+                Ok(previous) => Some(Ok(fold_func(&previous, &evaluated_deps))),
+                Err(error) => Some(Err(error)),
+            };
+        }
+        let func = self.compute_cell_funcs.get(&id)?;
+        Some(func(&evaluated_deps))
+    }
+
+    /// The number of dependency hops between a cell and the nearest input.
+    /// Recomputing dirty cells in increasing depth order guarantees every
+    /// dependency is up to date before its dependents run.
+    fn depth(&self, id: CellID, memo: &mut HashMap<CellID, usize>) -> usize {
+        if let Some(&depth) = memo.get(&id) {
+            return depth;
+        }
+        let depth = self
+            .graph
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .map(|&dep| self.depth(dep, memo))
+            .max()
+            .map_or(0, |deepest| deepest + 1);
+        memo.insert(id, depth);
+        depth
+    }
+
     // Retrieves the current value of the cell, or None if the cell does not exist.
     //
     // You may wonder whether it is possible to implement `get(&self, id: CellID) -> Option<&Cell>`
@@ -156,23 +421,97 @@ where
     // It turns out this introduces a significant amount of extra complexity to this exercise.
     // We chose not to cover this here, since this exercise is probably enough work as-is.
     pub fn value(&self, id: CellID) -> Option<T> {
+        self.value_result(id).and_then(Result::ok)
+    }
+
+    // Retrieves the current value or error state of the cell, or None if the
+    // cell does not exist. Input cells are never in an error state.
+    pub fn value_result(&self, id: CellID) -> Option<Result<T, ComputeError>> {
+        self.settle();
+        self.cached_result(id)
+    }
+
+    fn cached_result(&self, id: CellID) -> Option<Result<T, ComputeError>> {
         match id {
-            CellID::Input(input_cell_id) => self.input_values.get(&input_cell_id).map(|&id| id),
+            CellID::Input(input_cell_id) => self.input_values.get(&input_cell_id).cloned().map(Ok),
             CellID::Compute(compute_cell_id) => self
-                .compute_cell_funcs
+                .compute_values
+                .read()
+                .unwrap()
                 .get(&compute_cell_id)
-                .and_then(|(func, _)| {
-                    let mut evaluated_deps = vec![];
-                    for &dep in self.graph[&id].iter() {
-                        if let Some(dep_value) = self.value(dep) {
-                            evaluated_deps.push(dep_value);
-                        } else {
-                            return None;
-                        }
+                .cloned(),
+        }
+    }
+
+    /// Recompute cells marked dirty by lazy `set_value` calls, dependencies
+    /// before dependents. Callback-worthy changes are queued for the next
+    /// [`Reactor::flush`]; tracked-cell edge refreshes are deferred the same
+    /// way, since both need `&mut self`.
+    fn settle(&self) {
+        let dirty = std::mem::take(&mut *self.dirty.write().unwrap());
+        if dirty.is_empty() {
+            return;
+        }
+        let mut dirty = dirty.into_iter().collect::<Vec<_>>();
+        let mut depths = HashMap::new();
+        dirty.sort_by_key(|&cell| self.depth(CellID::Compute(cell), &mut depths));
+
+        for cell in dirty {
+            let new_value = if self.tracked_funcs.contains_key(&cell) {
+                let (value, reads) = self.run_tracked(cell);
+                self.pending_tracked_edges
+                    .write()
+                    .unwrap()
+                    .push((cell, reads));
+                Some(Ok(value))
+            } else {
+                self.evaluate(cell)
+            };
+            if let Some(new_value) = new_value {
+                let old_value = self
+                    .compute_values
+                    .write()
+                    .unwrap()
+                    .insert(cell, new_value.clone());
+                // The cache always holds the latest result; equality only
+                // decides whether callbacks hear about it.
+                let changed = match old_value.as_ref() {
+                    Some(old_value) => !self.values_equal(cell, old_value, &new_value),
+                    None => true,
+                };
+                if changed {
+                    let mut pending = self.pending_changes.write().unwrap();
+                    match pending
+                        .iter_mut()
+                        .find(|(pending_cell, _, _)| *pending_cell == cell)
+                    {
+                        Some(entry) => entry.2 = new_value,
+                        None => pending.push((cell, old_value, new_value)),
                     }
+                }
+            }
+        }
+    }
 
-                    Some(func(&evaluated_deps))
-                }),
+    /// Whether a cell's old and new results count as unchanged for
+    /// callback suppression. Custom equality only applies between two `Ok`
+    /// values; error states always compare structurally.
+    fn values_equal(
+        &self,
+        cell: ComputeCellID,
+        old: &Result<T, ComputeError>,
+        new: &Result<T, ComputeError>,
+    ) -> bool {
+        match (self.eq_funcs.get(&cell), old, new) {
+            (Some(eq_func), Ok(old), Ok(new)) => eq_func(old, new),
+            _ => old == new,
+        }
+    }
+
+    fn apply_pending_tracked_edges(&mut self) {
+        let edges = std::mem::take(&mut *self.pending_tracked_edges.write().unwrap());
+        for (cell, reads) in edges {
+            self.set_tracked_edges(cell, reads);
         }
     }
 
@@ -203,45 +542,291 @@ where
     //
     // As before, that turned out to add too much extra complexity.
     pub fn set_value(&mut self, id: InputCellID, new_value: T) -> bool {
-        let input_cell = CellID::Input(id);
-        if self.input_values.contains_key(&id) {
-            let mut current_values = vec![];
-            let mut cells_to_compute = vec![];
-            for &compute_cell_id in self.compute_cell_funcs.keys() {
-                if self.depends_on(CellID::Compute(compute_cell_id), input_cell) {
-                    cells_to_compute.push(compute_cell_id);
-                }
+        let old_value = match self.input_values.get(&id) {
+            Some(old_value) => old_value.clone(),
+            None => return false,
+        };
+        if !self.apply_input(id, new_value.clone()) {
+            return false;
+        }
+        self.undo_stack.push_back((id, old_value, new_value));
+        if self.undo_stack.len() > self.history_limit {
+            self.undo_stack.pop_front();
+        }
+        self.redo_stack.clear();
+        true
+    }
+
+    /// Limit how many input changes are kept for [`Reactor::undo`]. Older
+    /// entries are discarded first; a limit of zero disables history.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        while self.undo_stack.len() > limit {
+            self.undo_stack.pop_front();
+        }
+    }
+
+    /// Roll the most recent [`Reactor::set_value`] back, re-firing callbacks
+    /// just as the original change did. Returns false if there is nothing to
+    /// undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop_back() {
+            Some((id, old_value, new_value)) => {
+                self.apply_input(id, old_value.clone());
+                self.redo_stack.push((id, old_value, new_value));
+                true
             }
+            None => false,
+        }
+    }
 
-            for &cell in cells_to_compute.iter() {
-                current_values.push((cell, self.value(CellID::Compute(cell))));
+    /// Re-apply the most recently undone change. The redo stack is cleared
+    /// by the next ordinary `set_value`. Returns false if there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some((id, old_value, new_value)) => {
+                self.apply_input(id, new_value.clone());
+                self.undo_stack.push_back((id, old_value, new_value));
+                true
             }
+            None => false,
+        }
+    }
 
-            self.input_values.insert(id, new_value);
+    /// Capture the current state of every cell. The result is ordered by
+    /// cell ID, so snapshots of the same state compare equal.
+    pub fn snapshot(&self) -> ReactorSnapshot<T> {
+        let mut inputs = self
+            .input_values
+            .iter()
+            .map(|(&id, value)| (id, value.clone()))
+            .collect::<Vec<_>>();
+        inputs.sort_by_key(|&(id, _)| id.id);
+        self.settle();
+        let mut computes = self
+            .compute_values
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(&id, value)| (id, value.clone()))
+            .collect::<Vec<_>>();
+        computes.sort_by_key(|&(id, _)| id.id);
+        ReactorSnapshot { inputs, computes }
+    }
 
-            let mut cells_to_callback = vec![];
-            for (cell, current_value) in current_values.into_iter() {
-                let new_value = self.value(CellID::Compute(cell));
-                if new_value != current_value {
-                    if let Some(new_value) = new_value {
-                        cells_to_callback.push((cell, new_value));
-                    }
-                }
+    /// Replace the reactor's state wholesale with a snapshot. Every cell in
+    /// the snapshot must still exist here (snapshots are bound by ID, so
+    /// this is the same reactor or one that created the same cells in the
+    /// same order); otherwise nothing is changed and false is returned.
+    ///
+    /// Cells created after the snapshot was taken keep their functions and
+    /// are recomputed against the restored values. Restoring is not an
+    /// input change: callbacks do not fire and the undo history is cleared.
+    pub fn restore(&mut self, snapshot: &ReactorSnapshot<T>) -> bool {
+        if snapshot
+            .inputs
+            .iter()
+            .any(|(id, _)| !self.input_values.contains_key(id))
+            || snapshot
+                .computes
+                .iter()
+                .any(|&(id, _)| !self.compute_values.read().unwrap().contains_key(&id))
+        {
+            return false;
+        }
+
+        for (id, value) in snapshot.inputs.iter() {
+            self.input_values.insert(*id, value.clone());
+        }
+        let snapshotted = snapshot
+            .computes
+            .iter()
+            .map(|&(id, _)| id)
+            .collect::<HashSet<_>>();
+        for (id, value) in snapshot.computes.iter() {
+            self.compute_values
+                .write()
+                .unwrap()
+                .insert(*id, value.clone());
+        }
+
+        // Cells the snapshot doesn't cover are stale against the restored
+        // inputs; bring them up to date, dependencies before dependents.
+        let mut stale = self
+            .compute_values
+            .read()
+            .unwrap()
+            .keys()
+            .copied()
+            .filter(|cell| !snapshotted.contains(cell))
+            .collect::<Vec<_>>();
+        let mut depths = HashMap::new();
+        stale.sort_by_key(|&cell| self.depth(CellID::Compute(cell), &mut depths));
+        for cell in stale {
+            let value = if self.tracked_funcs.contains_key(&cell) {
+                let (value, reads) = self.run_tracked(cell);
+                self.set_tracked_edges(cell, reads);
+                Some(Ok(value))
+            } else {
+                self.evaluate(cell)
+            };
+            if let Some(value) = value {
+                self.compute_values.write().unwrap().insert(cell, value);
             }
+        }
+
+        self.dirty.write().unwrap().clear();
+        self.pending_changes.write().unwrap().clear();
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        true
+    }
 
-            for (cell_to_callback, new_value) in cells_to_callback.into_iter() {
-                if let Some((_, callbacks)) = self.compute_cell_funcs.get_mut(&cell_to_callback) {
-                    for callback in callbacks.values_mut() {
-                        (callback)(new_value);
+    /// Choose when compute cells are recomputed. Switching back to
+    /// [`EvaluationPolicy::Eager`] flushes anything the lazy policy
+    /// deferred.
+    pub fn set_evaluation_policy(&mut self, policy: EvaluationPolicy) {
+        self.policy = policy;
+        if policy == EvaluationPolicy::Eager {
+            self.flush();
+        }
+    }
+
+    /// Bring every cell up to date and fire callbacks for the changes that
+    /// have accumulated since the last flush. Under the eager policy this
+    /// happens inside every `set_value`; under the lazy policy reads settle
+    /// values on their own but callbacks wait here.
+    pub fn flush(&mut self) {
+        self.settle();
+        self.apply_pending_tracked_edges();
+        let changes = std::mem::take(&mut *self.pending_changes.write().unwrap());
+        for (cell_to_callback, old_value, new_value) in changes.into_iter() {
+            // Coalesced changes that ended up back at the original value
+            // are not changes at all.
+            if old_value
+                .as_ref()
+                .is_some_and(|old_value| self.values_equal(cell_to_callback, old_value, &new_value))
+            {
+                continue;
+            }
+            match new_value {
+                Ok(new_value) => {
+                    if let Some(callbacks) = self.value_callbacks.get_mut(&cell_to_callback) {
+                        for callback in callbacks.values_mut() {
+                            (callback)(new_value.clone());
+                        }
+                    }
+                    if let (Some(callbacks), Some(Ok(old_value))) =
+                        (self.full_callbacks.get_mut(&cell_to_callback), old_value)
+                    {
+                        for callback in callbacks.values_mut() {
+                            (callback)(cell_to_callback, old_value.clone(), new_value.clone());
+                        }
+                    }
+                }
+                Err(error) => {
+                    if let Some(callbacks) = self.error_callbacks.get_mut(&cell_to_callback) {
+                        for callback in callbacks.values_mut() {
+                            (callback)(error.clone());
+                        }
                     }
                 }
             }
-            true
-        } else {
-            false
         }
     }
 
+    fn apply_input(&mut self, id: InputCellID, new_value: T) -> bool {
+        let input_cell = CellID::Input(id);
+        if !self.input_values.contains_key(&id) {
+            return false;
+        }
+
+        self.input_values.insert(id, new_value);
+        self.purge_dropped_subscriptions();
+        self.apply_pending_tracked_edges();
+
+        // Only cells downstream of the changed input are dirty; each will be
+        // recomputed exactly once per settle, dependencies before
+        // dependents.
+        let downstream = self
+            .compute_values
+            .read()
+            .unwrap()
+            .keys()
+            .copied()
+            .filter(|&cell| self.depends_on(CellID::Compute(cell), input_cell))
+            .collect::<Vec<_>>();
+        self.dirty.write().unwrap().extend(downstream);
+        if self.policy == EvaluationPolicy::Eager {
+            self.flush();
+        }
+        true
+    }
+
+    /// Render the dependency graph as Graphviz DOT. Input cells are boxes,
+    /// compute cells are ellipses, and edges point in the direction data
+    /// flows. Output is deterministic: nodes and edges are ordered by cell
+    /// ID.
+    pub fn to_dot(&self) -> String {
+        self.render_dot(|_| None)
+    }
+
+    /// Like [`Reactor::to_dot`], but node labels include each cell's
+    /// current value.
+    pub fn to_dot_with_values(&self) -> String
+    where
+        T: std::fmt::Debug,
+    {
+        self.render_dot(|id| self.value(id).map(|value| format!("{:?}", value)))
+    }
+
+    fn render_dot(&self, label: impl Fn(CellID) -> Option<String>) -> String {
+        fn name(id: CellID) -> String {
+            match id {
+                CellID::Input(input) => format!("input_{}", input.id),
+                CellID::Compute(compute) => format!("compute_{}", compute.id),
+            }
+        }
+
+        fn sort_key(id: CellID) -> usize {
+            match id {
+                CellID::Input(input) => input.id,
+                CellID::Compute(compute) => compute.id,
+            }
+        }
+
+        let mut nodes = self.graph.keys().copied().collect::<Vec<_>>();
+        nodes.sort_by_key(|&id| sort_key(id));
+
+        let mut dot = String::from("digraph reactor {\n");
+        for &id in nodes.iter() {
+            let shape = match id {
+                CellID::Input(_) => "box",
+                CellID::Compute(_) => "ellipse",
+            };
+            match label(id) {
+                Some(value) => dot.push_str(&format!(
+                    "    {} [shape={}, label=\"{} = {}\"];\n",
+                    name(id),
+                    shape,
+                    name(id),
+                    value
+                )),
+                None => dot.push_str(&format!("    {} [shape={}];\n", name(id), shape)),
+            }
+        }
+        for &id in nodes.iter() {
+            let mut deps = self.graph[&id].clone();
+            deps.sort_by_key(|&dep| sort_key(dep));
+            for dep in deps {
+                dot.push_str(&format!("    {} -> {};\n", name(dep), name(id)));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
     // Adds a callback to the specified compute cell.
     //
     // Returns the ID of the just-added callback, or None if the cell doesn't exist.
@@ -258,13 +843,40 @@ where
     where
         F: FnMut(T) + 'a,
     {
-        if let Some((_, callbacks)) = self.compute_cell_funcs.get_mut(&id) {
-            let callback_id = CallbackID::new();
-            callbacks.insert(callback_id, Box::new(callback));
-            Some(callback_id)
-        } else {
-            None
+        if !self.is_compute_cell(id) {
+            return None;
         }
+        let callback_id = CallbackID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
+        self.value_callbacks
+            .entry(id)
+            .or_default()
+            .insert(callback_id, Box::new(callback));
+        Some(callback_id)
+    }
+
+    // Adds a callback that only fires when the cell's new value satisfies
+    // the predicate, so filtering doesn't have to be re-encoded inside every
+    // callback. The predicate runs on every change; the callback only on
+    // matching ones. Returns None if the cell doesn't exist.
+    pub fn add_callback_when<P, F>(
+        &mut self,
+        id: ComputeCellID,
+        predicate: P,
+        callback: F,
+    ) -> Option<CallbackID>
+    where
+        P: Fn(&T) -> bool + 'a,
+        F: FnMut(T) + 'a,
+    {
+        let mut callback = callback;
+        self.add_callback(id, move |value| {
+            if predicate(&value) {
+                callback(value);
+            }
+        })
     }
 
     // Removes the specified callback, using an ID returned from add_callback.
@@ -277,16 +889,176 @@ where
         cell: ComputeCellID,
         callback: CallbackID,
     ) -> Result<(), RemoveCallbackError> {
-        if self
-            .compute_cell_funcs
+        if !self.is_compute_cell(cell) {
+            return Err(RemoveCallbackError::NonexistentCell);
+        }
+        let removed = self
+            .value_callbacks
             .get_mut(&cell)
-            .ok_or(RemoveCallbackError::NonexistentCell)?
-            .1
-            .remove(&callback)
-            .is_none()
-        {
+            .is_some_and(|callbacks| callbacks.remove(&callback).is_some())
+            || self
+                .error_callbacks
+                .get_mut(&cell)
+                .is_some_and(|callbacks| callbacks.remove(&callback).is_some())
+            || self
+                .full_callbacks
+                .get_mut(&cell)
+                .is_some_and(|callbacks| callbacks.remove(&callback).is_some());
+        if !removed {
             return Err(RemoveCallbackError::NonexistentCallback);
         }
         Ok(())
     }
+
+    // Adds a callback wrapped in an RAII guard: dropping the returned
+    // `Subscription` unregisters the callback, so observers that go away
+    // cannot leak. The guard's `id()` also works with `remove_callback`.
+    pub fn add_callback_guarded<F>(
+        &mut self,
+        id: ComputeCellID,
+        callback: F,
+    ) -> Option<Subscription>
+    where
+        F: FnMut(T) + 'a,
+    {
+        let alive = Rc::new(Cell::new(true));
+        let flag = Rc::clone(&alive);
+        let mut callback = callback;
+        let callback_id = self.add_callback(id, move |value| {
+            if flag.get() {
+                callback(value);
+            }
+        })?;
+        self.subscription_flags
+            .push((id, callback_id, Rc::clone(&alive)));
+        Some(Subscription {
+            cell: id,
+            callback: callback_id,
+            alive,
+        })
+    }
+
+    /// Unregister callbacks whose guards have been dropped since the last
+    /// input change.
+    fn purge_dropped_subscriptions(&mut self) {
+        let mut flags = std::mem::take(&mut self.subscription_flags);
+        flags.retain(|(cell, callback, alive)| {
+            if alive.get() {
+                true
+            } else {
+                // Ignore errors: the callback may already have been removed
+                // by ID.
+                let _ = self.remove_callback(*cell, *callback);
+                false
+            }
+        });
+        self.subscription_flags = flags;
+    }
+
+    // Subscribes to a compute cell through a channel: every change to the
+    // cell's value is sent to the returned receiver, which can live on
+    // another thread. Send errors from a dropped receiver are ignored.
+    // Returns None if the cell doesn't exist.
+    pub fn subscribe(&mut self, id: ComputeCellID) -> Option<std::sync::mpsc::Receiver<T>>
+    where
+        T: 'a,
+    {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.add_callback(id, move |value| {
+            let _ = sender.send(value);
+        })?;
+        Some(receiver)
+    }
+
+    // Adds a callback that also receives the cell's ID and previous value,
+    // so one closure can be shared across many cells. Returns None if the
+    // cell doesn't exist.
+    pub fn add_callback_full<F>(&mut self, id: ComputeCellID, callback: F) -> Option<CallbackID>
+    where
+        F: FnMut(ComputeCellID, T, T) + 'a,
+    {
+        if !self.is_compute_cell(id) {
+            return None;
+        }
+        let callback_id = CallbackID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
+        self.full_callbacks
+            .entry(id)
+            .or_default()
+            .insert(callback_id, Box::new(callback));
+        Some(callback_id)
+    }
+
+    // Adds a callback fired when the cell transitions into a new error
+    // state. Returns None if the cell doesn't exist.
+    pub fn add_error_callback<F>(&mut self, id: ComputeCellID, callback: F) -> Option<CallbackID>
+    where
+        F: FnMut(ComputeError) + 'a,
+    {
+        if !self.is_compute_cell(id) {
+            return None;
+        }
+        let callback_id = CallbackID {
+            reactor: self.id,
+            id: self.next_object_id(),
+        };
+        self.error_callbacks
+            .entry(id)
+            .or_default()
+            .insert(callback_id, Box::new(callback));
+        Some(callback_id)
+    }
+}
+
+/// An RAII guard for a callback registered with
+/// [`Reactor::add_callback_guarded`]. Dropping it deactivates the callback
+/// immediately; the reactor reclaims the slot on the next input change.
+#[derive(Debug)]
+pub struct Subscription {
+    cell: ComputeCellID,
+    callback: CallbackID,
+    alive: Rc<Cell<bool>>,
+}
+
+impl Subscription {
+    /// The compute cell this subscription observes.
+    pub fn cell(&self) -> ComputeCellID {
+        self.cell
+    }
+
+    /// The underlying callback ID, usable with `remove_callback`.
+    pub fn id(&self) -> CallbackID {
+        self.callback
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.alive.set(false);
+    }
+}
+
+/// The read handle passed to tracked compute closures. Every `get` is
+/// recorded so the reactor can maintain the cell's dependency edges
+/// automatically.
+pub struct TrackingContext<'r, 'a, T> {
+    reactor: &'r Reactor<'a, T>,
+    reads: RefCell<Vec<CellID>>,
+}
+
+impl<T> TrackingContext<'_, '_, T>
+where
+    T: Clone + PartialEq,
+{
+    /// Read a cell's current value, recording the dependency.
+    pub fn get(&self, id: CellID) -> Option<T> {
+        let mut reads = self.reads.borrow_mut();
+        if !reads.contains(&id) {
+            reads.push(id);
+        }
+        drop(reads);
+        self.reactor.value(id)
+    }
 }
@@ -0,0 +1,117 @@
+//! A thread-safe handle around [`Reactor`].
+//!
+//! The reactor sits behind an [`RwLock`]: `set_value` and everything else
+//! that reshapes the graph take the write lock, while `value` and
+//! `value_result` share the read lock, so any number of threads can read
+//! concurrently. The handle keeps the reactor on the eager policy, so the
+//! graph is fully settled before `set_value` releases the write lock and
+//! reads are plain cache lookups. Callbacks run on the thread that called
+//! `set_value`, while that thread still holds the write lock, so they must
+//! not call back into the same reactor.
+
+use crate::{
+    CallbackID, CellID, ComputeCellID, ComputeError, CreateComputeError, InputCellID, Reactor,
+    RemoveCallbackError,
+};
+use std::sync::RwLock;
+
+pub struct SyncReactor<T> {
+    inner: RwLock<Reactor<'static, T>>,
+}
+
+// SAFETY: the inner reactor stores unmarked `Box<dyn Fn...>` trait objects,
+// but every closure enters through a `SyncReactor` method that requires
+// `Send`. No other construction path exists, so moving the handle across
+// threads cannot observe a non-Send closure. Sharing is sound because every
+// path that mutates the reactor -- including invoking the stored closures
+// -- holds the write lock, and the read paths only touch the reactor's
+// value caches, which are sync locks (not `RefCell`s) precisely so that
+// concurrent readers are well defined.
+unsafe impl<T: Send> Send for SyncReactor<T> {}
+unsafe impl<T: Send + Sync> Sync for SyncReactor<T> {}
+
+impl<T> SyncReactor<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(Reactor::new()),
+        }
+    }
+
+    pub fn create_input(&self, initial: T) -> InputCellID {
+        self.inner.write().unwrap().create_input(initial)
+    }
+
+    pub fn create_compute<F>(
+        &self,
+        dependencies: &[CellID],
+        compute_func: F,
+    ) -> Result<ComputeCellID, CreateComputeError>
+    where
+        F: Fn(&[T]) -> T + Send + Sync + 'static,
+    {
+        self.inner
+            .write()
+            .unwrap()
+            .create_compute(dependencies, compute_func)
+    }
+
+    pub fn create_compute_fallible<F>(
+        &self,
+        dependencies: &[CellID],
+        compute_func: F,
+    ) -> Result<ComputeCellID, CreateComputeError>
+    where
+        F: Fn(&[T]) -> Result<T, ComputeError> + Send + Sync + 'static,
+    {
+        self.inner
+            .write()
+            .unwrap()
+            .create_compute_fallible(dependencies, compute_func)
+    }
+
+    pub fn value(&self, id: CellID) -> Option<T> {
+        self.inner.read().unwrap().value(id)
+    }
+
+    pub fn value_result(&self, id: CellID) -> Option<Result<T, ComputeError>> {
+        self.inner.read().unwrap().value_result(id)
+    }
+
+    pub fn set_value(&self, id: InputCellID, new_value: T) -> bool {
+        self.inner.write().unwrap().set_value(id, new_value)
+    }
+
+    pub fn add_callback<F>(&self, id: ComputeCellID, callback: F) -> Option<CallbackID>
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        self.inner.write().unwrap().add_callback(id, callback)
+    }
+
+    pub fn add_error_callback<F>(&self, id: ComputeCellID, callback: F) -> Option<CallbackID>
+    where
+        F: FnMut(ComputeError) + Send + 'static,
+    {
+        self.inner.write().unwrap().add_error_callback(id, callback)
+    }
+
+    pub fn remove_callback(
+        &self,
+        cell: ComputeCellID,
+        callback: CallbackID,
+    ) -> Result<(), RemoveCallbackError> {
+        self.inner.write().unwrap().remove_callback(cell, callback)
+    }
+}
+
+impl<T> Default for SyncReactor<T>
+where
+    T: Clone + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
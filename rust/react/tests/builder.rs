@@ -0,0 +1,82 @@
+use react::builder::{BuildError, ReactorBuilder};
+use react::*;
+
+#[test]
+fn builds_a_named_graph() {
+    let (reactor, names) = ReactorBuilder::new()
+        .input("a", 1)
+        .input("b", 2)
+        .compute("sum", &["a", "b"], |v| v[0] + v[1])
+        .compute("double_sum", &["sum"], |v| v[0] * 2)
+        .build()
+        .unwrap();
+
+    assert_eq!(reactor.value(names["sum"]), Some(3));
+    assert_eq!(reactor.value(names["double_sum"]), Some(6));
+}
+
+#[test]
+fn built_reactors_are_ordinary_reactors() {
+    let (mut reactor, names) = ReactorBuilder::new()
+        .input("a", 1)
+        .compute("negated", &["a"], |v| -v[0])
+        .build()
+        .unwrap();
+
+    let input = match names["a"] {
+        CellID::Input(input) => input,
+        CellID::Compute(_) => unreachable!("\"a\" was declared as an input"),
+    };
+    reactor.set_value(input, 5);
+    assert_eq!(reactor.value(names["negated"]), Some(-5));
+}
+
+#[test]
+fn unknown_dependency_names_are_rejected() {
+    let result = ReactorBuilder::new()
+        .input("a", 1)
+        .compute("sum", &["a", "b"], |v| v[0] + v[1])
+        .build();
+    assert_eq!(result.err(), Some(BuildError::UnknownCell("b".to_string())));
+}
+
+#[test]
+fn cells_must_be_declared_before_use() {
+    let result = ReactorBuilder::new()
+        .compute("early", &["late"], |v| v[0])
+        .input("late", 1)
+        .build();
+    assert_eq!(
+        result.err(),
+        Some(BuildError::UnknownCell("late".to_string()))
+    );
+}
+
+#[test]
+fn duplicate_names_are_rejected() {
+    let result = ReactorBuilder::new().input("a", 1).input("a", 2).build();
+    assert_eq!(
+        result.err(),
+        Some(BuildError::DuplicateName("a".to_string()))
+    );
+}
+
+#[test]
+fn fallible_cells_work_through_the_builder() {
+    let (reactor, names) = ReactorBuilder::new()
+        .input("denominator", 0)
+        .compute_fallible("quotient", &["denominator"], |v| {
+            if v[0] == 0 {
+                Err("division by zero".to_string())
+            } else {
+                Ok(100 / v[0])
+            }
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        reactor.value_result(names["quotient"]),
+        Some(Err("division by zero".to_string()))
+    );
+}
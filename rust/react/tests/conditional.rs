@@ -0,0 +1,50 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn callback_only_fires_when_predicate_matches() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    reactor.add_callback_when(double, |v| *v > 10, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 3);
+    reactor.set_value(input, 7);
+    reactor.set_value(input, 4);
+    reactor.set_value(input, 20);
+    assert_eq!(*seen.borrow(), [14, 40]);
+}
+
+#[test]
+fn conditional_callbacks_can_be_removed() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let identity = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0])
+        .unwrap();
+    let callback = reactor
+        .add_callback_when(identity, |v| *v != 0, |v| seen.borrow_mut().push(v))
+        .unwrap();
+    assert_eq!(reactor.remove_callback(identity, callback), Ok(()));
+
+    reactor.set_value(input, 5);
+    assert!(seen.borrow().is_empty());
+}
+
+#[test]
+fn returns_none_for_nonexistent_cells() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let cell = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0])
+        .unwrap();
+
+    let mut other = Reactor::new();
+    assert!(other
+        .add_callback_when(cell, |_: &i32| true, |_| ())
+        .is_none());
+}
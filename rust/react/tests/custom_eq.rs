@@ -0,0 +1,75 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn version_counter_equality_suppresses_payload_only_changes() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input((0u64, "payload".to_string()));
+    // Equality looks only at the version in the first slot, so payload
+    // churn without a version bump stays quiet.
+    let versioned = reactor
+        .create_compute_with_eq(
+            &[CellID::Input(input)],
+            |v| v[0].clone(),
+            |old, new| old.0 == new.0,
+        )
+        .unwrap();
+    reactor.add_callback(versioned, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, (0, "changed payload".to_string()));
+    assert!(seen.borrow().is_empty());
+
+    reactor.set_value(input, (1, "changed payload".to_string()));
+    assert_eq!(seen.borrow().len(), 1);
+}
+
+#[test]
+fn always_notify_fires_even_for_equal_values() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let clamped = reactor
+        .create_compute_with_eq(&[CellID::Input(input)], |v| v[0].min(10), |_, _| false)
+        .unwrap();
+    reactor.add_callback(clamped, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 20);
+    reactor.set_value(input, 30); // still clamped to 10
+    assert_eq!(*seen.borrow(), [10, 10]);
+}
+
+#[test]
+fn custom_equality_applies_to_lazy_coalescing() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input((0u64, 0i32));
+    let versioned = reactor
+        .create_compute_with_eq(&[CellID::Input(input)], |v| v[0], |old, new| old.0 == new.0)
+        .unwrap();
+    reactor.add_callback(versioned, |v| seen.borrow_mut().push(v));
+
+    // The data slot changes but the version doesn't: quiet after flush.
+    reactor.set_value(input, (0, 5));
+    reactor.flush();
+    assert!(seen.borrow().is_empty());
+
+    reactor.set_value(input, (1, 5));
+    reactor.flush();
+    assert_eq!(*seen.borrow(), [(1, 5)]);
+}
+
+#[test]
+fn plain_cells_keep_partialeq_change_detection() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let identity = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0])
+        .unwrap();
+    reactor.add_callback(identity, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 0);
+    assert!(seen.borrow().is_empty());
+}
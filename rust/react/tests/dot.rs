@@ -0,0 +1,35 @@
+use react::*;
+
+#[test]
+fn to_dot_lists_cells_and_edges_in_id_order() {
+    let mut reactor = Reactor::new();
+    let one = reactor.create_input(1);
+    let two = reactor.create_input(2);
+    reactor
+        .create_compute(&[CellID::Input(one), CellID::Input(two)], |v| v[0] + v[1])
+        .unwrap();
+
+    assert_eq!(
+        reactor.to_dot(),
+        "digraph reactor {\n\
+         \x20   input_0 [shape=box];\n\
+         \x20   input_1 [shape=box];\n\
+         \x20   compute_2 [shape=ellipse];\n\
+         \x20   input_0 -> compute_2;\n\
+         \x20   input_1 -> compute_2;\n\
+         }\n"
+    );
+}
+
+#[test]
+fn to_dot_with_values_labels_nodes() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(3);
+    reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+
+    let dot = reactor.to_dot_with_values();
+    assert!(dot.contains("input_0 [shape=box, label=\"input_0 = 3\"];"));
+    assert!(dot.contains("compute_1 [shape=ellipse, label=\"compute_1 = 6\"];"));
+}
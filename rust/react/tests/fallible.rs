@@ -0,0 +1,94 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn failing_cells_report_their_error_through_value_result() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let checked = reactor
+        .create_compute_fallible(&[CellID::Input(input)], |v| {
+            if v[0] == 0 {
+                Err("division by zero".to_string())
+            } else {
+                Ok(100 / v[0])
+            }
+        })
+        .unwrap();
+
+    assert_eq!(reactor.value(CellID::Compute(checked)), None);
+    assert_eq!(
+        reactor.value_result(CellID::Compute(checked)),
+        Some(Err("division by zero".to_string()))
+    );
+
+    reactor.set_value(input, 4);
+    assert_eq!(reactor.value(CellID::Compute(checked)), Some(25));
+}
+
+#[test]
+fn errors_propagate_to_downstream_cells() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let fallible = reactor
+        .create_compute_fallible(&[CellID::Input(input)], |v| {
+            if v[0] < 0 {
+                Err("negative".to_string())
+            } else {
+                Ok(v[0])
+            }
+        })
+        .unwrap();
+    let downstream = reactor
+        .create_compute(&[CellID::Compute(fallible)], |v| v[0] + 1)
+        .unwrap();
+
+    reactor.set_value(input, -1);
+    assert_eq!(
+        reactor.value_result(CellID::Compute(downstream)),
+        Some(Err("negative".to_string()))
+    );
+}
+
+#[test]
+fn error_callbacks_fire_on_error_transitions() {
+    let errors = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let fallible = reactor
+        .create_compute_fallible(&[CellID::Input(input)], |v| {
+            if v[0] < 0 {
+                Err("negative".to_string())
+            } else {
+                Ok(v[0])
+            }
+        })
+        .unwrap();
+    reactor.add_error_callback(fallible, |error| errors.borrow_mut().push(error));
+
+    reactor.set_value(input, -1);
+    reactor.set_value(input, -1);
+    assert_eq!(*errors.borrow(), ["negative".to_string()]);
+}
+
+#[test]
+fn error_callbacks_can_be_removed() {
+    let errors = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let fallible = reactor
+        .create_compute_fallible(&[CellID::Input(input)], |v| {
+            if v[0] < 0 {
+                Err("negative".to_string())
+            } else {
+                Ok(v[0])
+            }
+        })
+        .unwrap();
+    let callback = reactor
+        .add_error_callback(fallible, |error| errors.borrow_mut().push(error))
+        .unwrap();
+    assert_eq!(reactor.remove_callback(fallible, callback), Ok(()));
+
+    reactor.set_value(input, -1);
+    assert!(errors.borrow().is_empty());
+}
@@ -0,0 +1,62 @@
+use react::*;
+
+#[test]
+fn fold_cells_accumulate_across_changes() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let total = reactor
+        .create_fold(&[CellID::Input(input)], 0, |prev, v| prev + v[0])
+        .unwrap();
+
+    assert_eq!(reactor.value(CellID::Compute(total)), Some(0));
+    reactor.set_value(input, 3);
+    reactor.set_value(input, 4);
+    assert_eq!(reactor.value(CellID::Compute(total)), Some(7));
+}
+
+#[test]
+fn fold_cells_participate_in_the_graph_like_any_compute_cell() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let count = reactor
+        .create_fold(&[CellID::Input(input)], 0, |prev, _| prev + 1)
+        .unwrap();
+    let doubled = reactor
+        .create_compute(&[CellID::Compute(count)], |v| v[0] * 2)
+        .unwrap();
+
+    reactor.set_value(input, 10);
+    reactor.set_value(input, 20);
+    assert_eq!(reactor.value(CellID::Compute(doubled)), Some(4));
+}
+
+#[test]
+fn fold_cells_fire_callbacks_on_change() {
+    use std::cell::RefCell;
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let max = reactor
+        .create_fold(&[CellID::Input(input)], 0, |prev, v| (*prev).max(v[0]))
+        .unwrap();
+    reactor.add_callback(max, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 5);
+    reactor.set_value(input, 3); // max unchanged: no callback
+    reactor.set_value(input, 8);
+    assert_eq!(*seen.borrow(), [5, 8]);
+}
+
+#[test]
+fn fold_rejects_nonexistent_dependencies() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+
+    let mut other = Reactor::new();
+    assert_eq!(
+        other.create_fold(&[CellID::Input(input)], 0, |prev, v| prev + v[0]),
+        Err(CreateComputeError::NonexistentDependency(CellID::Input(
+            input
+        )))
+    );
+}
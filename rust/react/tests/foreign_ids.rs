@@ -0,0 +1,38 @@
+use react::*;
+
+#[test]
+fn ids_from_another_reactor_are_rejected() {
+    let mut first = Reactor::new();
+    let mut second = Reactor::new();
+    let foreign_input = first.create_input(1);
+    let foreign_compute = first
+        .create_compute(&[CellID::Input(foreign_input)], |v| v[0])
+        .unwrap();
+    // Give the second reactor cells of its own so the lookups have
+    // somewhere plausible to land.
+    let local = second.create_input(10);
+    second
+        .create_compute(&[CellID::Input(local)], |v| v[0])
+        .unwrap();
+
+    assert_eq!(second.value(CellID::Input(foreign_input)), None);
+    assert_eq!(second.value(CellID::Compute(foreign_compute)), None);
+    assert!(!second.set_value(foreign_input, 99));
+    assert!(second.add_callback(foreign_compute, |_| ()).is_none());
+    assert_eq!(
+        second.create_compute(&[CellID::Input(foreign_input)], |v| v[0]),
+        Err(CreateComputeError::NonexistentDependency(CellID::Input(
+            foreign_input
+        )))
+    );
+}
+
+#[test]
+fn each_reactor_numbers_cells_independently() {
+    let mut first = Reactor::new();
+    let mut second = Reactor::new();
+    let a = first.create_input(0);
+    let b = second.create_input(0);
+    // Same slot in different reactors must still be distinct IDs.
+    assert_ne!(CellID::Input(a), CellID::Input(b));
+}
@@ -0,0 +1,64 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn full_callbacks_receive_id_old_and_new() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let plus_one = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] + 1)
+        .unwrap();
+    reactor.add_callback_full(plus_one, |cell, old, new| {
+        observed.borrow_mut().push((cell, old, new))
+    });
+
+    reactor.set_value(input, 5);
+    assert_eq!(*observed.borrow(), [(plus_one, 2, 6)]);
+}
+
+#[test]
+fn one_closure_can_watch_many_cells() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    let triple = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 3)
+        .unwrap();
+
+    let log = &observed;
+    reactor.add_callback_full(double, move |cell, _, new| {
+        log.borrow_mut().push((cell, new))
+    });
+    let log = &observed;
+    reactor.add_callback_full(triple, move |cell, _, new| {
+        log.borrow_mut().push((cell, new))
+    });
+
+    reactor.set_value(input, 2);
+    let mut events = observed.borrow().clone();
+    events.sort_by_key(|&(_, new)| new);
+    assert_eq!(events, [(double, 4), (triple, 6)]);
+}
+
+#[test]
+fn full_callbacks_are_removable() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    let callback = reactor
+        .add_callback_full(double, |cell, old, new| {
+            observed.borrow_mut().push((cell, old, new))
+        })
+        .unwrap();
+    assert_eq!(reactor.remove_callback(double, callback), Ok(()));
+
+    reactor.set_value(input, 1);
+    assert!(observed.borrow().is_empty());
+}
@@ -0,0 +1,84 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn undo_rolls_inputs_back_one_change_at_a_time() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    reactor.set_value(input, 2);
+    reactor.set_value(input, 3);
+
+    assert!(reactor.undo());
+    assert_eq!(reactor.value(CellID::Input(input)), Some(2));
+    assert!(reactor.undo());
+    assert_eq!(reactor.value(CellID::Input(input)), Some(1));
+    assert!(!reactor.undo());
+}
+
+#[test]
+fn redo_reapplies_undone_changes() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    reactor.set_value(input, 2);
+
+    assert!(reactor.undo());
+    assert!(reactor.redo());
+    assert_eq!(reactor.value(CellID::Input(input)), Some(2));
+    assert!(!reactor.redo());
+}
+
+#[test]
+fn set_value_clears_the_redo_stack() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    reactor.set_value(input, 2);
+    reactor.undo();
+    reactor.set_value(input, 5);
+
+    assert!(!reactor.redo());
+    assert_eq!(reactor.value(CellID::Input(input)), Some(5));
+}
+
+#[test]
+fn undo_and_redo_fire_callbacks() {
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    reactor.add_callback(double, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 2);
+    reactor.undo();
+    reactor.redo();
+    assert_eq!(*seen.borrow(), [4, 2, 4]);
+}
+
+#[test]
+fn history_is_bounded() {
+    let mut reactor = Reactor::new();
+    reactor.set_history_limit(2);
+    let input = reactor.create_input(0);
+    for value in 1..=5 {
+        reactor.set_value(input, value);
+    }
+
+    assert!(reactor.undo());
+    assert!(reactor.undo());
+    assert!(!reactor.undo(), "only the two most recent changes are kept");
+    assert_eq!(reactor.value(CellID::Input(input)), Some(3));
+}
+
+#[test]
+fn histories_of_different_inputs_interleave() {
+    let mut reactor = Reactor::new();
+    let a = reactor.create_input(0);
+    let b = reactor.create_input(10);
+    reactor.set_value(a, 1);
+    reactor.set_value(b, 11);
+
+    assert!(reactor.undo());
+    assert_eq!(reactor.value(CellID::Input(b)), Some(10));
+    assert_eq!(reactor.value(CellID::Input(a)), Some(1));
+}
@@ -0,0 +1,84 @@
+use react::*;
+use std::cell::Cell;
+
+#[test]
+fn deep_chains_recompute_each_cell_exactly_once_per_change() {
+    let evaluations = Cell::new(0usize);
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+
+    let mut previous = CellID::Input(input);
+    for _ in 0..50 {
+        let counter = &evaluations;
+        let next = reactor
+            .create_compute(&[previous], move |v| {
+                counter.set(counter.get() + 1);
+                v[0] + 1
+            })
+            .unwrap();
+        previous = CellID::Compute(next);
+    }
+
+    // Each of the 50 cells runs once at creation time.
+    assert_eq!(evaluations.get(), 50);
+
+    reactor.set_value(input, 1);
+    assert_eq!(
+        evaluations.get(),
+        100,
+        "a single input change must evaluate each dirty cell exactly once"
+    );
+    assert_eq!(reactor.value(previous), Some(51));
+}
+
+#[test]
+fn wide_graphs_only_recompute_downstream_cells() {
+    let evaluations = Cell::new(0usize);
+    let mut reactor = Reactor::new();
+    let hot = reactor.create_input(0);
+    let cold = reactor.create_input(0);
+
+    for _ in 0..25 {
+        let counter = &evaluations;
+        reactor
+            .create_compute(&[CellID::Input(hot)], move |v| {
+                counter.set(counter.get() + 1);
+                v[0]
+            })
+            .unwrap();
+        let counter = &evaluations;
+        reactor
+            .create_compute(&[CellID::Input(cold)], move |v| {
+                counter.set(counter.get() + 1);
+                v[0]
+            })
+            .unwrap();
+    }
+
+    let after_creation = evaluations.get();
+    reactor.set_value(hot, 1);
+    assert_eq!(
+        evaluations.get() - after_creation,
+        25,
+        "cells hanging off the untouched input must not be recomputed"
+    );
+}
+
+#[test]
+fn value_reads_are_cached_and_never_recompute() {
+    let evaluations = Cell::new(0usize);
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let counter = &evaluations;
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], move |v| {
+            counter.set(counter.get() + 1);
+            v[0] * 2
+        })
+        .unwrap();
+
+    for _ in 0..10 {
+        assert_eq!(reactor.value(CellID::Compute(double)), Some(2));
+    }
+    assert_eq!(evaluations.get(), 1);
+}
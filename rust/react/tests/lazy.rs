@@ -0,0 +1,107 @@
+use react::*;
+use std::cell::Cell;
+
+#[test]
+fn lazy_reactors_defer_recomputation_until_read() {
+    let evaluations = Cell::new(0usize);
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input(0);
+    let counter = &evaluations;
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], move |v| {
+            counter.set(counter.get() + 1);
+            v[0] * 2
+        })
+        .unwrap();
+
+    let after_creation = evaluations.get();
+    reactor.set_value(input, 1);
+    reactor.set_value(input, 2);
+    reactor.set_value(input, 3);
+    assert_eq!(
+        evaluations.get(),
+        after_creation,
+        "no reads yet, so nothing recomputes"
+    );
+
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(6));
+    assert_eq!(
+        evaluations.get(),
+        after_creation + 1,
+        "three coalesced changes settle with a single evaluation"
+    );
+}
+
+#[test]
+fn lazy_callbacks_fire_on_flush_with_final_values() {
+    use std::cell::RefCell;
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    reactor.add_callback(double, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 1);
+    reactor.set_value(input, 5);
+    assert!(seen.borrow().is_empty(), "callbacks wait for flush");
+
+    reactor.flush();
+    assert_eq!(*seen.borrow(), [10], "coalesced to the final value only");
+}
+
+#[test]
+fn changes_that_settle_back_to_the_original_value_do_not_fire() {
+    use std::cell::RefCell;
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input(1);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    reactor.add_callback(double, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 5);
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(10));
+    reactor.set_value(input, 1);
+    reactor.flush();
+    assert!(seen.borrow().is_empty());
+}
+
+#[test]
+fn switching_back_to_eager_flushes_deferred_work() {
+    use std::cell::RefCell;
+    let seen = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    reactor.add_callback(double, |v| seen.borrow_mut().push(v));
+
+    reactor.set_value(input, 3);
+    reactor.set_evaluation_policy(EvaluationPolicy::Eager);
+    assert_eq!(*seen.borrow(), [6]);
+
+    reactor.set_value(input, 4);
+    assert_eq!(*seen.borrow(), [6, 8], "eager callbacks are prompt again");
+}
+
+#[test]
+fn lazy_fold_cells_fold_once_per_settle() {
+    let mut reactor = Reactor::new();
+    reactor.set_evaluation_policy(EvaluationPolicy::Lazy);
+    let input = reactor.create_input(0);
+    let latest = reactor
+        .create_fold(&[CellID::Input(input)], 0, |_, v| v[0])
+        .unwrap();
+
+    reactor.set_value(input, 1);
+    reactor.set_value(input, 2);
+    assert_eq!(reactor.value(CellID::Compute(latest)), Some(2));
+}
@@ -0,0 +1,37 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn string_cells_compute_and_propagate() {
+    let mut reactor = Reactor::new();
+    let name = reactor.create_input("world".to_string());
+    let greeting = reactor
+        .create_compute(&[CellID::Input(name)], |v| format!("hello, {}", v[0]))
+        .unwrap();
+    assert_eq!(
+        reactor.value(CellID::Compute(greeting)),
+        Some("hello, world".to_string())
+    );
+
+    reactor.set_value(name, "reactor".to_string());
+    assert_eq!(
+        reactor.value(CellID::Compute(greeting)),
+        Some("hello, reactor".to_string())
+    );
+}
+
+#[test]
+fn vec_cells_fire_callbacks_with_the_final_value() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let items = reactor.create_input(vec![1]);
+    let doubled = reactor
+        .create_compute(&[CellID::Input(items)], |v| {
+            v[0].iter().map(|&item| item * 2).collect::<Vec<i32>>()
+        })
+        .unwrap();
+    reactor.add_callback(doubled, |value| observed.borrow_mut().push(value));
+
+    reactor.set_value(items, vec![1, 2, 3]);
+    assert_eq!(*observed.borrow(), [vec![2, 4, 6]]);
+}
@@ -51,7 +51,9 @@ fn error_creating_compute_cell_if_input_doesnt_exist() {
     let input = dummy_reactor.create_input(1);
     assert_eq!(
         Reactor::new().create_compute(&[CellID::Input(input)], |_| 0),
-        Err(CellID::Input(input))
+        Err(CreateComputeError::NonexistentDependency(CellID::Input(
+            input
+        )))
     );
 }
 
@@ -64,7 +66,9 @@ fn do_not_break_cell_if_creating_compute_cell_with_valid_and_invalid_input() {
     let input = reactor.create_input(1);
     assert_eq!(
         reactor.create_compute(&[CellID::Input(input), CellID::Input(dummy_cell)], |_| 0),
-        Err(CellID::Input(dummy_cell))
+        Err(CreateComputeError::NonexistentDependency(CellID::Input(
+            dummy_cell
+        )))
     );
     assert!(reactor.set_value(input, 5));
     assert_eq!(reactor.value(CellID::Input(input)), Some(5));
@@ -0,0 +1,80 @@
+use react::*;
+
+#[test]
+fn restore_brings_back_snapshotted_values() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+
+    let snapshot = reactor.snapshot();
+    reactor.set_value(input, 10);
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(20));
+
+    assert!(reactor.restore(&snapshot));
+    assert_eq!(reactor.value(CellID::Input(input)), Some(1));
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(2));
+}
+
+#[test]
+fn snapshots_capture_accumulated_fold_state() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let total = reactor
+        .create_fold(&[CellID::Input(input)], 0, |prev, v| prev + v[0])
+        .unwrap();
+    reactor.set_value(input, 5);
+
+    let snapshot = reactor.snapshot();
+    reactor.set_value(input, 7);
+    assert_eq!(reactor.value(CellID::Compute(total)), Some(12));
+
+    assert!(reactor.restore(&snapshot));
+    assert_eq!(reactor.value(CellID::Compute(total)), Some(5));
+}
+
+#[test]
+fn cells_created_after_the_snapshot_are_recomputed() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let snapshot = reactor.snapshot();
+
+    reactor.set_value(input, 10);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(20));
+
+    assert!(reactor.restore(&snapshot));
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(2));
+}
+
+#[test]
+fn snapshots_do_not_restore_into_foreign_reactors() {
+    let mut reactor = Reactor::new();
+    reactor.create_input(1);
+    let snapshot = reactor.snapshot();
+
+    let mut other: Reactor<i32> = Reactor::new();
+    assert!(!other.restore(&snapshot));
+}
+
+#[test]
+fn restore_does_not_fire_callbacks_and_clears_history() {
+    use std::cell::Cell;
+    let calls = Cell::new(0);
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    let snapshot = reactor.snapshot();
+    reactor.add_callback(double, |_| calls.set(calls.get() + 1));
+
+    reactor.set_value(input, 10);
+    assert_eq!(calls.get(), 1);
+    assert!(reactor.restore(&snapshot));
+    assert_eq!(calls.get(), 1, "restore must not fire callbacks");
+    assert!(!reactor.undo(), "restore clears the undo history");
+}
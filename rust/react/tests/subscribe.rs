@@ -0,0 +1,60 @@
+use react::*;
+use std::sync::mpsc::TryRecvError;
+
+#[test]
+fn subscribers_receive_every_change() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let plus_one = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] + 1)
+        .unwrap();
+    let receiver = reactor.subscribe(plus_one).unwrap();
+
+    reactor.set_value(input, 2);
+    reactor.set_value(input, 5);
+    assert_eq!(receiver.try_recv(), Ok(3));
+    assert_eq!(receiver.try_recv(), Ok(6));
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[test]
+fn unchanged_values_send_nothing() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let clamped = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0].min(10))
+        .unwrap();
+    let receiver = reactor.subscribe(clamped).unwrap();
+
+    reactor.set_value(input, 20);
+    reactor.set_value(input, 30);
+    assert_eq!(receiver.try_recv(), Ok(10));
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn receivers_can_be_consumed_on_another_thread() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    let receiver = reactor.subscribe(double).unwrap();
+
+    let consumer = std::thread::spawn(move || receiver.recv().unwrap());
+    reactor.set_value(input, 21);
+    assert_eq!(consumer.join().unwrap(), 42);
+}
+
+#[test]
+fn dropping_the_receiver_does_not_break_updates() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+    drop(reactor.subscribe(double).unwrap());
+
+    assert!(reactor.set_value(input, 3));
+    assert_eq!(reactor.value(CellID::Compute(double)), Some(6));
+}
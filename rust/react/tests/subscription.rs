@@ -0,0 +1,60 @@
+use react::*;
+use std::cell::RefCell;
+
+#[test]
+fn dropping_the_guard_silences_the_callback() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let plus_one = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] + 1)
+        .unwrap();
+
+    let subscription = reactor
+        .add_callback_guarded(plus_one, |value| observed.borrow_mut().push(value))
+        .unwrap();
+    reactor.set_value(input, 2);
+    drop(subscription);
+    reactor.set_value(input, 3);
+
+    assert_eq!(*observed.borrow(), [3]);
+}
+
+#[test]
+fn the_guard_id_works_with_remove_callback() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let plus_one = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] + 1)
+        .unwrap();
+
+    let subscription = reactor
+        .add_callback_guarded(plus_one, |value| observed.borrow_mut().push(value))
+        .unwrap();
+    assert_eq!(subscription.cell(), plus_one);
+    assert_eq!(
+        reactor.remove_callback(subscription.cell(), subscription.id()),
+        Ok(())
+    );
+
+    reactor.set_value(input, 2);
+    assert!(observed.borrow().is_empty());
+}
+
+#[test]
+fn live_guards_keep_their_callbacks() {
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+
+    let _subscription = reactor
+        .add_callback_guarded(double, |value| observed.borrow_mut().push(value))
+        .unwrap();
+    reactor.set_value(input, 1);
+    reactor.set_value(input, 2);
+    assert_eq!(*observed.borrow(), [2, 4]);
+}
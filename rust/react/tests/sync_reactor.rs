@@ -0,0 +1,52 @@
+use react::sync::SyncReactor;
+use react::CellID;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn values_can_be_read_while_inputs_change() {
+    let reactor = Arc::new(SyncReactor::new());
+    let input = reactor.create_input(0);
+    let double = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] * 2)
+        .unwrap();
+
+    let readers = (0..4)
+        .map(|_| {
+            let reactor = Arc::clone(&reactor);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    let value = reactor.value(CellID::Compute(double)).unwrap();
+                    assert_eq!(value % 2, 0, "readers must never see a torn value");
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for i in 0..1000 {
+        assert!(reactor.set_value(input, i));
+    }
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
+
+#[test]
+fn callbacks_run_on_the_writing_thread() {
+    let reactor = Arc::new(SyncReactor::new());
+    let input = reactor.create_input(1);
+    let plus_one = reactor
+        .create_compute(&[CellID::Input(input)], |v| v[0] + 1)
+        .unwrap();
+
+    let writer_thread = thread::current().id();
+    let observed = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = Arc::clone(&observed);
+    reactor.add_callback(plus_one, move |value| {
+        assert_eq!(thread::current().id(), writer_thread);
+        sink.lock().unwrap().push(value);
+    });
+
+    reactor.set_value(input, 2);
+    assert_eq!(*observed.lock().unwrap(), [3]);
+}
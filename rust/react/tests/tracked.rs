@@ -0,0 +1,66 @@
+use react::*;
+
+#[test]
+fn tracked_cells_follow_the_cells_they_read() {
+    let mut reactor = Reactor::new();
+    let a = reactor.create_input(1);
+    let b = reactor.create_input(10);
+    let sum = reactor.create_compute_tracked(move |ctx| {
+        ctx.get(CellID::Input(a)).unwrap() + ctx.get(CellID::Input(b)).unwrap()
+    });
+
+    assert_eq!(reactor.value(CellID::Compute(sum)), Some(11));
+    reactor.set_value(a, 2);
+    assert_eq!(reactor.value(CellID::Compute(sum)), Some(12));
+    reactor.set_value(b, 20);
+    assert_eq!(reactor.value(CellID::Compute(sum)), Some(22));
+}
+
+#[test]
+fn edges_follow_the_branch_actually_taken() {
+    let mut reactor = Reactor::new();
+    let switch = reactor.create_input(0);
+    let left = reactor.create_input(100);
+    let right = reactor.create_input(200);
+    let chosen = reactor.create_compute_tracked(move |ctx| {
+        if ctx.get(CellID::Input(switch)).unwrap() == 0 {
+            ctx.get(CellID::Input(left)).unwrap()
+        } else {
+            ctx.get(CellID::Input(right)).unwrap()
+        }
+    });
+
+    assert_eq!(reactor.value(CellID::Compute(chosen)), Some(100));
+
+    // While the left branch is selected, the right input is not an edge,
+    // so changing it must not dirty the tracked cell.
+    reactor.set_value(right, 201);
+    assert_eq!(reactor.value(CellID::Compute(chosen)), Some(100));
+
+    reactor.set_value(switch, 1);
+    assert_eq!(reactor.value(CellID::Compute(chosen)), Some(201));
+    reactor.set_value(right, 202);
+    assert_eq!(reactor.value(CellID::Compute(chosen)), Some(202));
+}
+
+#[test]
+fn tracked_cells_support_callbacks() {
+    use std::cell::RefCell;
+    let observed = RefCell::new(Vec::new());
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    let double =
+        reactor.create_compute_tracked(move |ctx| ctx.get(CellID::Input(input)).unwrap() * 2);
+    reactor.add_callback(double, |value| observed.borrow_mut().push(value));
+
+    reactor.set_value(input, 3);
+    assert_eq!(*observed.borrow(), [6]);
+}
+
+#[test]
+fn tracked_cells_appear_in_dot_output() {
+    let mut reactor = Reactor::new();
+    let input = reactor.create_input(1);
+    reactor.create_compute_tracked(move |ctx| ctx.get(CellID::Input(input)).unwrap());
+    assert!(reactor.to_dot().contains("input_0 -> compute_1;"));
+}
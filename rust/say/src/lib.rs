@@ -1,4 +1,6 @@
 use itertools::{EitherOrBoth, Itertools};
+use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 fn ones(n: u64) -> Option<&'static str> {
@@ -46,7 +48,51 @@ fn tens(n: u64) -> Option<&'static str> {
     })
 }
 
-fn simple(n: u64) -> String {
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Style {
+    /// Short scale, no "and": "one hundred one".
+    American,
+    /// Inserts "and" after hundreds and before a final small group:
+    /// "one hundred and one", "one thousand and five".
+    British,
+}
+
+/// Which naming system scale groups use: the short scale advances by
+/// thousands (billion = 10^9), the long scale by millions with -illiard
+/// intermediates (milliard = 10^9, billion = 10^12).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scale {
+    Short,
+    Long,
+}
+
+/// Knobs for downstream style guides: hyphenation of compound tens,
+/// commas between scale groups, and leading capitalization.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub style: Style,
+    pub scale: Scale,
+    /// "twenty-one" when true, "twenty one" when false.
+    pub hyphenate: bool,
+    /// "one million, two thousand" when true.
+    pub group_commas: bool,
+    /// Uppercase the first letter of the result.
+    pub capitalize: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            style: Style::American,
+            scale: Scale::Short,
+            hyphenate: true,
+            group_commas: false,
+            capitalize: false,
+        }
+    }
+}
+
+fn simple(n: u64, options: Options) -> String {
     let hundreds_digit = n / 100;
     let tens_digit = (n - 100 * hundreds_digit) / 10;
     let ones_digit = n % 10;
@@ -69,7 +115,7 @@ fn simple(n: u64) -> String {
                 s.push_str(tens(tens_digit * 10).unwrap());
 
                 if ones_digit != 0 {
-                    s.push('-');
+                    s.push_str(if options.hyphenate { "-" } else { " " });
                 }
             }
 
@@ -79,6 +125,9 @@ fn simple(n: u64) -> String {
         }
 
         if !s.is_empty() {
+            if options.style == Style::British && !result.is_empty() {
+                result.push("and".to_owned());
+            }
             result.push(s);
         }
     }
@@ -103,13 +152,34 @@ pub fn split_thousands(mut chars: Vec<char>) -> Vec<u64> {
         .collect::<Vec<_>>()
 }
 
-const SCALES: [&str; 6] = [
+const SCALES: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+const LONG_SCALES: [&str; 12] = [
     "thousand",
     "million",
+    "milliard",
     "billion",
+    "billiard",
     "trillion",
+    "trilliard",
     "quadrillion",
+    "quadrilliard",
     "quintillion",
+    "quintilliard",
+    "sextillion",
 ];
 
 pub fn encode(n: u64) -> String {
@@ -117,11 +187,59 @@ pub fn encode(n: u64) -> String {
         return "zero".to_string();
     }
 
-    let s = n.to_string();
+    encode_options(n, Options::default())
+}
+
+pub fn encode_with(n: u64, style: Style) -> String {
+    encode_options(
+        n,
+        Options {
+            style,
+            ..Options::default()
+        },
+    )
+}
+
+pub fn encode_options(n: u64, options: Options) -> String {
+    let mut result = if n == 0 {
+        "zero".to_string()
+    } else {
+        encode_abs(n.to_string(), options)
+    };
+
+    if options.capitalize {
+        if let Some(first) = result.get(..1) {
+            result.replace_range(..1, &first.to_uppercase());
+        }
+    }
+
+    result
+}
+
+pub fn encode_signed(n: i128) -> String {
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    // `unsigned_abs` so that `i128::MIN` doesn't overflow on negation
+    let abs = n.unsigned_abs().to_string();
+    if n < 0 {
+        format!("negative {}", encode_abs(abs, Options::default()))
+    } else {
+        encode_abs(abs, Options::default())
+    }
+}
+
+fn encode_abs(s: String, options: Options) -> String {
     let splits = split_thousands(s.chars().collect::<Vec<_>>());
     let num_groups = splits.len();
+    let last_group = *splits.last().unwrap();
+    let scales: &[&str] = match options.scale {
+        Scale::Short => &SCALES,
+        Scale::Long => &LONG_SCALES,
+    };
 
-    splits
+    let mut parts = splits
         .into_iter()
         .map(|num| {
             if num == 0 {
@@ -131,12 +249,12 @@ pub fn encode(n: u64) -> String {
                 Some(if let Some(value) = ones(num).or_else(|| teens(num)) {
                     value.to_owned()
                 } else {
-                    simple(num)
+                    simple(num, options)
                 })
             }
         })
         .zip_longest(
-            SCALES[..num_groups - 1]
+            scales[..num_groups - 1]
                 .iter()
                 .rev()
                 .map(|&s| Some(s.to_owned())),
@@ -163,6 +281,387 @@ pub fn encode(n: u64) -> String {
                 EitherOrBoth::Right(_) => panic!("suffix exists but number text doesn't"),
             }
         })
+        .collect::<Vec<_>>();
+
+    // "one thousand and five": British puts an "and" before a final group
+    // that has no hundreds of its own
+    if options.style == Style::British && parts.len() > 1 && last_group > 0 && last_group < 100 {
+        let last = parts.last_mut().unwrap();
+        *last = format!("and {}", last);
+    }
+
+    parts.join(if options.group_commas { ", " } else { " " })
+}
+
+fn digit_name(c: char) -> Option<&'static str> {
+    if c == '0' {
+        Some("zero")
+    } else {
+        ones(c.to_digit(10)? as u64)
+    }
+}
+
+pub fn encode_decimal(input: &str) -> Option<String> {
+    let (input, negative) = match input.strip_prefix('-') {
+        Some(rest) => (rest, true),
+        None => (input, false),
+    };
+    let (int_part, frac_part) = match input.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (input, None),
+    };
+
+    if int_part.is_empty() || !int_part.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut result = if int_part.chars().all(|c| c == '0') {
+        "zero".to_string()
+    } else {
+        encode_abs(int_part.to_string(), Options::default())
+    };
+
+    if let Some(frac_part) = frac_part {
+        if frac_part.is_empty() {
+            return None;
+        }
+        result.push_str(" point");
+        for c in frac_part.chars() {
+            result.push(' ');
+            result.push_str(digit_name(c)?);
+        }
+    }
+
+    if negative {
+        result = format!("negative {}", result);
+    }
+
+    Some(result)
+}
+
+fn ordinal(n: u64) -> String {
+    let words = encode(n);
+    // only the final word changes: "twenty-two" -> "twenty-second"
+    let cut = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(cut);
+    let tail = match last {
+        "one" => "first".to_owned(),
+        "two" => "second".to_owned(),
+        "three" => "third".to_owned(),
+        "five" => "fifth".to_owned(),
+        "eight" => "eighth".to_owned(),
+        "nine" => "ninth".to_owned(),
+        "twelve" => "twelfth".to_owned(),
+        word if word.ends_with('y') => format!("{}ieth", &word[..word.len() - 1]),
+        word => format!("{}th", word),
+    };
+    format!("{}{}", head, tail)
+}
+
+pub fn encode_fraction(numerator: u64, denominator: u64) -> Option<String> {
+    let unit = match denominator {
+        0 => return None,
+        1 => "whole".to_owned(),
+        2 => "half".to_owned(),
+        4 => "quarter".to_owned(),
+        n => ordinal(n),
+    };
+    let unit = if numerator == 1 {
+        unit
+    } else if denominator == 2 {
+        "halves".to_owned()
+    } else {
+        format!("{}s", unit)
+    };
+    Some(format!("{} {}", encode(numerator), unit))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Gbp,
+    Eur,
+}
+
+impl Currency {
+    /// (singular, plural) names for the major and minor units.
+    fn units(self) -> ((&'static str, &'static str), (&'static str, &'static str)) {
+        match self {
+            Currency::Usd => (("dollar", "dollars"), ("cent", "cents")),
+            Currency::Gbp => (("pound", "pounds"), ("penny", "pence")),
+            Currency::Eur => (("euro", "euros"), ("cent", "cents")),
+        }
+    }
+}
+
+fn with_unit(n: u64, (singular, plural): (&str, &str)) -> String {
+    format!("{} {}", encode(n), if n == 1 { singular } else { plural })
+}
+
+pub fn encode_currency(cents: u64, currency: Currency) -> String {
+    let (major_unit, minor_unit) = currency.units();
+    let major = cents / 100;
+    let minor = cents % 100;
+
+    match (major, minor) {
+        (0, 0) => with_unit(0, major_unit),
+        (0, minor) => with_unit(minor, minor_unit),
+        (major, 0) => with_unit(major, major_unit),
+        (major, minor) => format!(
+            "{} and {}",
+            with_unit(major, major_unit),
+            with_unit(minor, minor_unit)
+        ),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input contained no number words at all.
+    Empty,
+    /// A token that isn't part of any English number name.
+    UnknownWord(String),
+    /// A valid number word in a position the grammar doesn't allow,
+    /// e.g. "one two" or "thousand million".
+    UnexpectedWord(String),
+    /// The phrase names a number that doesn't fit in a `u64`.
+    Overflow,
+}
+
+fn word_value(word: &str) -> Option<u64> {
+    (1..=9)
+        .find(|&n| ones(n) == Some(word))
+        .or_else(|| (11..=19).find(|&n| teens(n) == Some(word)))
+        .or_else(|| (1..=9).map(|n| n * 10).find(|&n| tens(n) == Some(word)))
+}
+
+pub fn decode(input: &str) -> Result<u64, DecodeError> {
+    let tokens = input
+        .split_whitespace()
+        .flat_map(|word| word.split('-'))
+        .collect::<Vec<_>>();
+
+    if tokens.is_empty() {
+        return Err(DecodeError::Empty);
+    }
+    if tokens == ["zero"] {
+        return Ok(0);
+    }
+
+    let mut total = 0u128;
+    let mut group = 0u64;
+    let mut last_scale = None;
+
+    for token in tokens {
+        let unexpected = || DecodeError::UnexpectedWord(token.to_string());
+
+        if token == "and" {
+            continue;
+        } else if let Some(value) = word_value(token) {
+            let valid_slot = match value {
+                1..=9 => group.is_multiple_of(10) && !(10..=19).contains(&(group % 100)),
+                _ => group.is_multiple_of(100),
+            };
+            if !valid_slot {
+                return Err(unexpected());
+            }
+            group += value;
+        } else if token == "hundred" {
+            if !(1..=9).contains(&group) {
+                return Err(unexpected());
+            }
+            group *= 100;
+        } else if let Some(position) = SCALES.iter().position(|&scale| scale == token) {
+            let exponent = 3 * (position as u32 + 1);
+            if group == 0 || last_scale.is_some_and(|last| exponent >= last) {
+                return Err(unexpected());
+            }
+            let term = (group as u128)
+                .checked_mul(10u128.pow(exponent))
+                .ok_or(DecodeError::Overflow)?;
+            total = total.checked_add(term).ok_or(DecodeError::Overflow)?;
+            group = 0;
+            last_scale = Some(exponent);
+        } else {
+            return Err(DecodeError::UnknownWord(token.to_string()));
+        }
+    }
+
+    if total == 0 && group == 0 {
+        // nothing but "and"s
+        return Err(DecodeError::Empty);
+    }
+
+    u64::try_from(total + group as u128).map_err(|_| DecodeError::Overflow)
+}
+
+/// Spell one group of up to three digits, streaming words straight into
+/// `writer` instead of building intermediate strings.
+fn write_group(writer: &mut impl fmt::Write, n: u64) -> fmt::Result {
+    let hundreds = n / 100;
+    let rest = n % 100;
+
+    if hundreds != 0 {
+        writer.write_str(ones(hundreds).unwrap())?;
+        writer.write_str(" hundred")?;
+        if rest != 0 {
+            writer.write_char(' ')?;
+        }
+    }
+
+    if let Some(word) = ones(rest).or_else(|| teens(rest)).or_else(|| tens(rest)) {
+        writer.write_str(word)?;
+    } else if rest != 0 {
+        writer.write_str(tens(rest - rest % 10).unwrap())?;
+        writer.write_char('-')?;
+        writer.write_str(ones(rest % 10).unwrap())?;
+    }
+
+    Ok(())
+}
+
+/// Streaming equivalent of [`encode`]: no `Vec`s, no `join`s, just words
+/// written to `writer` as they're produced.
+pub fn encode_to(n: u64, writer: &mut impl fmt::Write) -> fmt::Result {
+    if n == 0 {
+        return writer.write_str("zero");
+    }
+
+    let mut first = true;
+    for scale in (0..7).rev() {
+        let group = (n / 1000u64.pow(scale)) % 1000;
+        if group == 0 {
+            continue;
+        }
+        if !first {
+            writer.write_char(' ')?;
+        }
+        first = false;
+        write_group(writer, group)?;
+        if scale > 0 {
+            writer.write_char(' ')?;
+            writer.write_str(SCALES[scale as usize - 1])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adapter so numbers can be spelled in place inside `format!`/`write!`
+/// without allocating: `format!("{} bottles", Spelled(99))`.
+pub struct Spelled(pub u64);
+
+impl fmt::Display for Spelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        encode_to(self.0, f)
+    }
+}
+
+/// How a zero digit is read out: "oh" is usual for phone numbers,
+/// "zero" for account numbers and aviation-style readback.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ZeroStyle {
+    Oh,
+    Zero,
+}
+
+impl ZeroStyle {
+    fn name(self) -> &'static str {
+        match self {
+            ZeroStyle::Oh => "oh",
+            ZeroStyle::Zero => "zero",
+        }
+    }
+}
+
+fn spoken_digit(c: char, zero: ZeroStyle) -> Option<&'static str> {
+    if c == '0' {
+        Some(zero.name())
+    } else {
+        ones(c.to_digit(10)? as u64)
+    }
+}
+
+pub fn encode_digits_with(n: u64, zero: ZeroStyle) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| spoken_digit(c, zero).unwrap())
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+pub fn encode_digits(n: u64) -> String {
+    encode_digits_with(n, ZeroStyle::Oh)
+}
+
+/// Read a phone or account number group by group: digits are spoken
+/// individually, runs of non-digits act as group separators, and groups
+/// are joined with commas ("555-0123" -> "five five five, oh one two three").
+pub fn encode_digit_groups(input: &str, zero: ZeroStyle) -> Option<String> {
+    let groups = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            group
+                .chars()
+                .map(|c| spoken_digit(c, zero).unwrap())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+
+    if groups.is_empty() {
+        None
+    } else {
+        Some(groups.join(", "))
+    }
+}
+
+const IRREGULAR_PLURALS: [(&str, &str); 10] = [
+    ("child", "children"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("sheep", "sheep"),
+    ("tooth", "teeth"),
+    ("woman", "women"),
+];
+
+fn pluralize(noun: &str) -> String {
+    if let Some(&(_, plural)) = IRREGULAR_PLURALS
+        .iter()
+        .find(|&&(singular, _)| singular == noun)
+    {
+        return plural.to_owned();
+    }
+
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| noun.ends_with(suffix))
+    {
+        format!("{}es", noun)
+    } else if noun.ends_with('y')
+        && !noun
+            .chars()
+            .rev()
+            .nth(1)
+            .is_some_and(|c| "aeiou".contains(c))
+    {
+        format!("{}ies", &noun[..noun.len() - 1])
+    } else {
+        format!("{}s", noun)
+    }
+}
+
+/// "ninety-nine bottles": a spelled-out count plus a correctly pluralized
+/// noun, so templating code doesn't have to hand-roll plural rules.
+pub fn quantity(n: u64, noun: &str) -> String {
+    if n == 1 {
+        format!("one {}", noun)
+    } else {
+        format!("{} {}", encode(n), pluralize(noun))
+    }
+}
@@ -0,0 +1,38 @@
+use say::{encode_with, Style};
+
+#[test]
+fn american_matches_encode() {
+    assert_eq!(encode_with(120, Style::American), say::encode(120));
+}
+
+#[test]
+fn hundred_and_one() {
+    assert_eq!(encode_with(101, Style::British), "one hundred and one");
+}
+
+#[test]
+fn hundred_and_twenty_three() {
+    assert_eq!(
+        encode_with(123, Style::British),
+        "one hundred and twenty-three"
+    );
+}
+
+#[test]
+fn thousand_and_five() {
+    assert_eq!(encode_with(1005, Style::British), "one thousand and five");
+}
+
+#[test]
+fn and_only_before_a_small_final_group() {
+    assert_eq!(
+        encode_with(1234, Style::British),
+        "one thousand two hundred and thirty-four"
+    );
+}
+
+#[test]
+fn round_numbers_take_no_and() {
+    assert_eq!(encode_with(1000, Style::British), "one thousand");
+    assert_eq!(encode_with(100, Style::British), "one hundred");
+}
@@ -0,0 +1,52 @@
+use say::{encode_currency, Currency};
+
+#[test]
+fn dollars_and_cents() {
+    assert_eq!(
+        encode_currency(80005, Currency::Usd),
+        "eight hundred dollars and five cents"
+    );
+}
+
+#[test]
+fn whole_dollars_elide_the_cents() {
+    assert_eq!(encode_currency(300, Currency::Usd), "three dollars");
+}
+
+#[test]
+fn cents_only_elide_the_dollars() {
+    assert_eq!(encode_currency(5, Currency::Usd), "five cents");
+}
+
+#[test]
+fn singular_units() {
+    assert_eq!(
+        encode_currency(101, Currency::Usd),
+        "one dollar and one cent"
+    );
+}
+
+#[test]
+fn zero_is_zero_dollars() {
+    assert_eq!(encode_currency(0, Currency::Usd), "zero dollars");
+}
+
+#[test]
+fn british_pence_are_irregular() {
+    assert_eq!(
+        encode_currency(201, Currency::Gbp),
+        "two pounds and one penny"
+    );
+    assert_eq!(
+        encode_currency(250, Currency::Gbp),
+        "two pounds and fifty pence"
+    );
+}
+
+#[test]
+fn euros() {
+    assert_eq!(
+        encode_currency(199, Currency::Eur),
+        "one euro and ninety-nine cents"
+    );
+}
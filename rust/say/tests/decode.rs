@@ -0,0 +1,76 @@
+use say::{decode, encode, DecodeError};
+
+#[test]
+fn decodes_scale_groups() {
+    assert_eq!(
+        decode("one million two hundred thirty-four thousand"),
+        Ok(1_234_000)
+    );
+}
+
+#[test]
+fn round_trips_encode() {
+    for &n in &[
+        0,
+        7,
+        14,
+        20,
+        22,
+        100,
+        120,
+        1002,
+        1_002_345,
+        987_654_321_123,
+        u64::MAX,
+    ] {
+        assert_eq!(decode(&encode(n)), Ok(n), "round-tripping {}", n);
+    }
+}
+
+#[test]
+fn british_and_is_accepted() {
+    assert_eq!(decode("one hundred and one"), Ok(101));
+}
+
+#[test]
+fn unknown_words_are_reported() {
+    assert_eq!(
+        decode("one zillion"),
+        Err(DecodeError::UnknownWord("zillion".to_string()))
+    );
+}
+
+#[test]
+fn misplaced_words_are_reported() {
+    assert_eq!(
+        decode("one two"),
+        Err(DecodeError::UnexpectedWord("two".to_string()))
+    );
+    assert_eq!(
+        decode("one thousand million"),
+        Err(DecodeError::UnexpectedWord("million".to_string()))
+    );
+    assert_eq!(
+        decode("hundred"),
+        Err(DecodeError::UnexpectedWord("hundred".to_string()))
+    );
+    assert_eq!(
+        decode("ten zero"),
+        Err(DecodeError::UnknownWord("zero".to_string()))
+    );
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(decode(""), Err(DecodeError::Empty));
+    assert_eq!(decode("   "), Err(DecodeError::Empty));
+}
+
+#[test]
+fn values_past_u64_overflow() {
+    assert_eq!(decode("one sextillion"), Err(DecodeError::Overflow));
+    assert_eq!(
+        decode("eighteen quintillion four hundred quadrillion"),
+        Ok(18_400_000_000_000_000_000)
+    );
+}
@@ -0,0 +1,40 @@
+use say::{encode_digit_groups, encode_digits, encode_digits_with, ZeroStyle};
+
+#[test]
+fn jennys_number() {
+    assert_eq!(encode_digits(8675309), "eight six seven five three oh nine");
+}
+
+#[test]
+fn zero_style_is_configurable() {
+    assert_eq!(encode_digits_with(101, ZeroStyle::Oh), "one oh one");
+    assert_eq!(encode_digits_with(101, ZeroStyle::Zero), "one zero one");
+}
+
+#[test]
+fn single_zero() {
+    assert_eq!(encode_digits(0), "oh");
+    assert_eq!(encode_digits_with(0, ZeroStyle::Zero), "zero");
+}
+
+#[test]
+fn phone_numbers_group_on_separators() {
+    assert_eq!(
+        encode_digit_groups("555-0123", ZeroStyle::Oh),
+        Some("five five five, oh one two three".to_string())
+    );
+}
+
+#[test]
+fn arbitrary_separators_and_runs() {
+    assert_eq!(
+        encode_digit_groups("(01) 23", ZeroStyle::Zero),
+        Some("zero one, two three".to_string())
+    );
+}
+
+#[test]
+fn no_digits_is_none() {
+    assert_eq!(encode_digit_groups("abc", ZeroStyle::Oh), None);
+    assert_eq!(encode_digit_groups("", ZeroStyle::Oh), None);
+}
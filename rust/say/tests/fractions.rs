@@ -0,0 +1,60 @@
+use say::{encode_decimal, encode_fraction};
+
+#[test]
+fn pi_to_two_places() {
+    assert_eq!(encode_decimal("3.14"), Some("three point one four".into()));
+}
+
+#[test]
+fn fractional_zeros_are_read_out() {
+    assert_eq!(
+        encode_decimal("120.05"),
+        Some("one hundred twenty point zero five".into())
+    );
+}
+
+#[test]
+fn plain_integers_have_no_point() {
+    assert_eq!(encode_decimal("42"), Some("forty-two".into()));
+    assert_eq!(encode_decimal("0"), Some("zero".into()));
+}
+
+#[test]
+fn negative_decimals() {
+    assert_eq!(
+        encode_decimal("-0.5"),
+        Some("negative zero point five".into())
+    );
+}
+
+#[test]
+fn malformed_decimals_are_rejected() {
+    assert_eq!(encode_decimal("3."), None);
+    assert_eq!(encode_decimal(".5"), None);
+    assert_eq!(encode_decimal("1.2.3"), None);
+    assert_eq!(encode_decimal("abc"), None);
+}
+
+#[test]
+fn irregular_fractions() {
+    assert_eq!(encode_fraction(1, 2), Some("one half".into()));
+    assert_eq!(encode_fraction(3, 2), Some("three halves".into()));
+    assert_eq!(encode_fraction(1, 4), Some("one quarter".into()));
+    assert_eq!(encode_fraction(3, 4), Some("three quarters".into()));
+}
+
+#[test]
+fn regular_fractions_use_ordinals() {
+    assert_eq!(encode_fraction(2, 3), Some("two thirds".into()));
+    assert_eq!(encode_fraction(1, 5), Some("one fifth".into()));
+    assert_eq!(encode_fraction(5, 8), Some("five eighths".into()));
+    assert_eq!(encode_fraction(7, 9), Some("seven ninths".into()));
+    assert_eq!(encode_fraction(1, 20), Some("one twentieth".into()));
+    assert_eq!(encode_fraction(2, 22), Some("two twenty-seconds".into()));
+}
+
+#[test]
+fn degenerate_denominators() {
+    assert_eq!(encode_fraction(1, 0), None);
+    assert_eq!(encode_fraction(3, 1), Some("three wholes".into()));
+}
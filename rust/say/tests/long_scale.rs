@@ -0,0 +1,35 @@
+use say::{encode_options, Options, Scale};
+
+const LONG: Options = Options {
+    style: say::Style::American,
+    scale: Scale::Long,
+    hyphenate: true,
+    group_commas: false,
+    capitalize: false,
+};
+
+#[test]
+fn milliard_replaces_billion() {
+    assert_eq!(encode_options(1_000_000_000, LONG), "one milliard");
+}
+
+#[test]
+fn long_scale_billion_is_ten_to_the_twelfth() {
+    assert_eq!(encode_options(1_000_000_000_000, LONG), "one billion");
+}
+
+#[test]
+fn billiard_and_trillion() {
+    assert_eq!(encode_options(2_000_000_000_000_000, LONG), "two billiard");
+    assert_eq!(
+        encode_options(3_000_000_000_000_000_000, LONG),
+        "three trillion"
+    );
+}
+
+#[test]
+fn below_a_milliard_the_scales_agree() {
+    for &n in &[0, 999, 1_000_000, 987_654_321] {
+        assert_eq!(encode_options(n, LONG), say::encode(n));
+    }
+}
@@ -0,0 +1,51 @@
+use say::{encode_options, Options, Style};
+
+#[test]
+fn defaults_match_encode() {
+    for &n in &[0, 22, 120, 1_002_345] {
+        assert_eq!(encode_options(n, Options::default()), say::encode(n));
+    }
+}
+
+#[test]
+fn hyphenation_can_be_disabled() {
+    let options = Options {
+        hyphenate: false,
+        ..Options::default()
+    };
+    assert_eq!(encode_options(21, options), "twenty one");
+}
+
+#[test]
+fn commas_between_scale_groups() {
+    let options = Options {
+        group_commas: true,
+        ..Options::default()
+    };
+    assert_eq!(
+        encode_options(1_002_000, options),
+        "one million, two thousand"
+    );
+    assert_eq!(encode_options(123, options), "one hundred twenty-three");
+}
+
+#[test]
+fn capitalization() {
+    let options = Options {
+        capitalize: true,
+        ..Options::default()
+    };
+    assert_eq!(encode_options(101, options), "One hundred one");
+    assert_eq!(encode_options(0, options), "Zero");
+}
+
+#[test]
+fn options_compose_with_british_style() {
+    let options = Options {
+        style: Style::British,
+        group_commas: true,
+        capitalize: true,
+        ..Options::default()
+    };
+    assert_eq!(encode_options(1_000_005, options), "One million, and five");
+}
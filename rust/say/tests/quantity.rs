@@ -0,0 +1,28 @@
+use say::quantity;
+
+#[test]
+fn bottles_of_beer() {
+    assert_eq!(quantity(99, "bottle"), "ninety-nine bottles");
+    assert_eq!(quantity(1, "bottle"), "one bottle");
+    assert_eq!(quantity(0, "bottle"), "zero bottles");
+}
+
+#[test]
+fn sibilant_endings_take_es() {
+    assert_eq!(quantity(2, "box"), "two boxes");
+    assert_eq!(quantity(3, "match"), "three matches");
+    assert_eq!(quantity(4, "bus"), "four buses");
+}
+
+#[test]
+fn consonant_y_becomes_ies() {
+    assert_eq!(quantity(2, "penny"), "two pennies");
+    assert_eq!(quantity(2, "day"), "two days");
+}
+
+#[test]
+fn irregular_plurals_come_from_the_override_table() {
+    assert_eq!(quantity(2, "child"), "two children");
+    assert_eq!(quantity(5, "person"), "five people");
+    assert_eq!(quantity(7, "sheep"), "seven sheep");
+}
@@ -0,0 +1,27 @@
+use say::encode_signed;
+
+#[test]
+fn negative_numbers_get_a_negative_prefix() {
+    assert_eq!(encode_signed(-123), "negative one hundred twenty-three");
+}
+
+#[test]
+fn zero_is_unchanged() {
+    assert_eq!(encode_signed(0), "zero");
+}
+
+#[test]
+fn positive_numbers_match_encode() {
+    assert_eq!(encode_signed(1002010), say::encode(1002010));
+}
+
+#[test]
+fn negative_one() {
+    assert_eq!(encode_signed(-1), "negative one");
+}
+
+#[test]
+fn extremes_do_not_overflow() {
+    assert!(encode_signed(i128::MIN).starts_with("negative one hundred seventy undecillion"));
+    assert!(encode_signed(i128::MAX).starts_with("one hundred seventy undecillion"));
+}
@@ -0,0 +1,36 @@
+use say::{encode, encode_to, Spelled};
+
+#[test]
+fn encode_to_matches_encode() {
+    for &n in &[
+        0,
+        7,
+        10,
+        14,
+        20,
+        22,
+        100,
+        120,
+        1002,
+        1_000_000,
+        1_002_345,
+        987_654_321_123,
+        u64::MAX,
+    ] {
+        let mut spelled = String::new();
+        encode_to(n, &mut spelled).unwrap();
+        assert_eq!(spelled, encode(n), "streaming {}", n);
+    }
+}
+
+#[test]
+fn spelled_implements_display() {
+    assert_eq!(format!("{} bottles", Spelled(99)), "ninety-nine bottles");
+}
+
+#[test]
+fn display_matches_encode_everywhere() {
+    for n in (0..20).chain((0..64).map(|shift| u64::MAX >> shift)) {
+        assert_eq!(Spelled(n).to_string(), encode(n));
+    }
+}
@@ -0,0 +1,52 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::io::Write;
+use xorcism::Xorcism;
+
+const KEY: &str = "a moderately sized key";
+const LEN: usize = 1 << 20;
+
+fn bench_munge_in_place(c: &mut Criterion) {
+    let mut group = c.benchmark_group("munge_in_place");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    group.bench_function("1MiB", |b| {
+        let mut data = vec![0xa5u8; LEN];
+        let mut munger = Xorcism::new(KEY);
+        b.iter(|| munger.munge_in_place(&mut data));
+    });
+    group.finish();
+}
+
+fn bench_munge_collect(c: &mut Criterion) {
+    let mut group = c.benchmark_group("munge_collect");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    group.bench_function("1MiB", |b| {
+        let data = vec![0xa5u8; LEN];
+        let mut munger = Xorcism::new(KEY);
+        b.iter(|| munger.munge(&data).collect::<Vec<u8>>());
+    });
+    group.finish();
+}
+
+fn bench_writer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("writer");
+    group.throughput(Throughput::Bytes(LEN as u64));
+    group.bench_function("1MiB", |b| {
+        let data = vec![0xa5u8; LEN];
+        b.iter(|| {
+            let mut out = Vec::with_capacity(LEN);
+            let mut writer = Xorcism::new(KEY).writer(&mut out);
+            writer.write_all(&data).unwrap();
+            drop(writer);
+            out
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_munge_in_place,
+    bench_munge_collect,
+    bench_writer
+);
+criterion_main!(benches);
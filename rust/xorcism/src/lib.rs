@@ -9,59 +9,105 @@ use xorcism_io::{XorcismReader, XorcismWriter};
 /// A munger which XORs a key with some data
 #[derive(Clone)]
 pub struct Xorcism<'a> {
-    key: std::iter::Cycle<std::slice::Iter<'a, u8>>,
+    key: &'a [u8],
+    /// Absolute number of bytes munged so far; the key byte used next is
+    /// `key[offset % key.len()]`.
+    offset: u64,
+}
+
+/// What happens to the cycle position when [`Xorcism::rekey`] swaps keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rekey {
+    /// Start the new key from its first byte.
+    Reset,
+    /// Keep the absolute offset, as if the new key had been in use from
+    /// the start of the stream.
+    Preserve,
+}
+
+/// A stateful byte-stream transform. The reader/writer adapters are
+/// generic over this, so other simple stream ciphers can reuse the same
+/// plumbing.
+pub trait Munger {
+    /// Transform `data` in place, advancing the internal state by
+    /// `data.len()` bytes.
+    fn transform(&mut self, data: &mut [u8]);
+}
+
+impl Munger for Xorcism<'_> {
+    fn transform(&mut self, data: &mut [u8]) {
+        self.munge_in_place(data);
+    }
 }
 
 #[cfg(feature = "io")]
 pub mod xorcism_io {
-    use super::Xorcism;
-    use std::io::{self, Read, Write};
+    use super::{Munger, Xorcism};
+    use std::io::{self, Read, Seek, SeekFrom, Write};
 
-    pub struct XorcismReader<'a, R> {
-        xorcism: Xorcism<'a>,
+    pub struct XorcismReader<M, R> {
+        munger: M,
         reader: R,
     }
 
-    impl<'a, R> XorcismReader<'a, R> {
-        pub fn new(xorcism: Xorcism<'a>, reader: R) -> Self {
-            Self { xorcism, reader }
+    impl<M, R> XorcismReader<M, R> {
+        pub fn new(munger: M, reader: R) -> Self {
+            Self { munger, reader }
         }
     }
 
-    pub struct XorcismWriter<'a, W> {
-        xorcism: Xorcism<'a>,
+    pub struct XorcismWriter<M, W> {
+        munger: M,
         writer: W,
     }
 
-    impl<'a, W> XorcismWriter<'a, W> {
-        pub fn new(xorcism: Xorcism<'a>, writer: W) -> Self {
-            Self { xorcism, writer }
+    impl<M, W> XorcismWriter<M, W> {
+        pub fn new(munger: M, writer: W) -> Self {
+            Self { munger, writer }
         }
     }
 
-    impl<'a, R> Read for XorcismReader<'a, R>
+    impl<M, R> Read for XorcismReader<M, R>
     where
+        M: Munger,
         R: Read,
     {
         fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-            self.reader.read(buf).map(|n| {
-                self.xorcism.munge_in_place(buf);
-                n
-            })
+            let n = self.reader.read(buf)?;
+            self.munger.transform(&mut buf[..n]);
+            Ok(n)
         }
     }
 
-    impl<'a, W> Write for XorcismWriter<'a, W>
+    /// Seeking moves the key cycle along with the underlying reader, so
+    /// random-access reads of an XOR-obfuscated file decode correctly.
+    /// Only `Xorcism` can jump its state to an arbitrary offset, so this
+    /// is not available for other mungers.
+    impl<'a, R> Seek for XorcismReader<Xorcism<'a>, R>
     where
+        R: Read + Seek,
+    {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            let offset = self.reader.seek(pos)?;
+            self.munger.set_position(offset);
+            Ok(offset)
+        }
+    }
+
+    impl<M, W> Write for XorcismWriter<M, W>
+    where
+        M: Munger + Clone,
         W: Write,
     {
         fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-            let writer = &mut self.writer;
-            self.xorcism
-                .munge(buf)
-                .try_fold(0_usize, move |total, byte| {
-                    Ok(total + writer.write(&[byte])?)
-                })
+            // transform a trial copy so a partial write only advances
+            // the munger by the bytes the inner writer actually took
+            let mut munged = buf.to_vec();
+            self.munger.clone().transform(&mut munged);
+            let n = self.writer.write(&munged)?;
+            let mut accepted = buf[..n].to_vec();
+            self.munger.transform(&mut accepted);
+            Ok(n)
         }
 
         fn flush(&mut self) -> io::Result<()> {
@@ -70,8 +116,426 @@ pub mod xorcism_io {
     }
 }
 
-pub trait Captures<'a> {}
-impl<'a, T> Captures<'a> for T {}
+#[cfg(feature = "async-io")]
+pub mod xorcism_async {
+    use super::Munger;
+    use futures_io::{AsyncRead, AsyncWrite};
+    use std::{
+        io,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// Async counterpart of `XorcismReader`: bytes are munged as they
+    /// arrive from the inner reader.
+    pub struct AsyncXorcismReader<M, R> {
+        munger: M,
+        reader: R,
+    }
+
+    impl<M, R> AsyncXorcismReader<M, R> {
+        pub fn new(munger: M, reader: R) -> Self {
+            Self { munger, reader }
+        }
+    }
+
+    /// Async counterpart of `XorcismWriter`: bytes are munged on their
+    /// way to the inner writer.
+    pub struct AsyncXorcismWriter<M, W> {
+        munger: M,
+        writer: W,
+    }
+
+    impl<M, W> AsyncXorcismWriter<M, W> {
+        pub fn new(munger: M, writer: W) -> Self {
+            Self { munger, writer }
+        }
+    }
+
+    impl<M, R> AsyncRead for AsyncXorcismReader<M, R>
+    where
+        M: Munger + Unpin,
+        R: AsyncRead + Unpin,
+    {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = &mut *self;
+            match Pin::new(&mut this.reader).poll_read(cx, buf) {
+                Poll::Ready(Ok(n)) => {
+                    this.munger.transform(&mut buf[..n]);
+                    Poll::Ready(Ok(n))
+                }
+                other => other,
+            }
+        }
+    }
+
+    impl<M, W> AsyncWrite for AsyncXorcismWriter<M, W>
+    where
+        M: Munger + Clone + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = &mut *self;
+            // transform a trial copy first: the inner writer may accept
+            // only part of the buffer, and the munger must advance by
+            // exactly the number of bytes it took
+            let mut munged = buf.to_vec();
+            this.munger.clone().transform(&mut munged);
+            match Pin::new(&mut this.writer).poll_write(cx, &munged) {
+                Poll::Ready(Ok(n)) => {
+                    let mut accepted = buf[..n].to_vec();
+                    this.munger.transform(&mut accepted);
+                    Poll::Ready(Ok(n))
+                }
+                other => other,
+            }
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.writer).poll_flush(cx)
+        }
+
+        fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.writer).poll_close(cx)
+        }
+    }
+}
+
+#[cfg(feature = "io")]
+pub mod files {
+    //! Whole-file munging done safely once: stream through the munger
+    //! into a temp file, fsync, then atomically rename over the
+    //! destination, so a crash never leaves a half-written output.
+
+    use super::Xorcism;
+    use std::fs::{self, File};
+    use std::io::{self, BufReader, BufWriter, Write};
+    use std::path::{Path, PathBuf};
+
+    /// Munge `src` into `dst`, returning the number of bytes written.
+    /// XOR is its own inverse, so this both encrypts and decrypts.
+    pub fn encrypt_file<K>(src: impl AsRef<Path>, dst: impl AsRef<Path>, key: &K) -> io::Result<u64>
+    where
+        K: AsRef<[u8]> + ?Sized,
+    {
+        encrypt_file_with_progress(src, dst, key, |_| {})
+    }
+
+    /// Like [`encrypt_file`], calling `progress` with the running byte
+    /// count after each chunk.
+    pub fn encrypt_file_with_progress<K>(
+        src: impl AsRef<Path>,
+        dst: impl AsRef<Path>,
+        key: &K,
+        progress: impl FnMut(u64),
+    ) -> io::Result<u64>
+    where
+        K: AsRef<[u8]> + ?Sized,
+    {
+        let dst = dst.as_ref();
+        let tmp = temp_path(dst);
+        let result = write_munged(src.as_ref(), &tmp, key.as_ref(), progress)
+            .and_then(|written| fs::rename(&tmp, dst).map(|()| written));
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        result
+    }
+
+    /// Munge `path` over itself. The temp-file dance makes this safe: the
+    /// original is intact until the final rename.
+    pub fn encrypt_file_in_place<K>(path: impl AsRef<Path>, key: &K) -> io::Result<u64>
+    where
+        K: AsRef<[u8]> + ?Sized,
+    {
+        let path = path.as_ref();
+        encrypt_file(path, path, key)
+    }
+
+    fn write_munged(
+        src: &Path,
+        tmp: &Path,
+        key: &[u8],
+        progress: impl FnMut(u64),
+    ) -> io::Result<u64> {
+        let reader = BufReader::new(File::open(src)?);
+        let mut file = File::create(tmp)?;
+        let mut writer = BufWriter::new(&mut file);
+        let written = Xorcism::new(key).copy_with_progress(reader, &mut writer, progress)?;
+        writer.flush()?;
+        drop(writer);
+        file.sync_all()?;
+        Ok(written)
+    }
+
+    /// The temp file lives next to `dst` so the rename stays on one
+    /// filesystem and therefore atomic.
+    fn temp_path(dst: &Path) -> PathBuf {
+        let mut name = dst.file_name().unwrap_or_default().to_os_string();
+        name.push(".tmp");
+        dst.with_file_name(name)
+    }
+}
+
+#[cfg(feature = "kdf")]
+pub mod kdf {
+    //! Passphrase-based key derivation, so callers stop XORing with raw
+    //! short ASCII strings directly.
+
+    use hmac::Hmac;
+    use sha2::Sha256;
+
+    const ROUNDS: u32 = 10_000;
+
+    /// An owned key derived from a passphrase. It can't be a borrowed
+    /// [`Xorcism`](super::Xorcism) itself — the munger borrows its key —
+    /// so keep the `DerivedKey` alive and borrow mungers from it.
+    pub struct DerivedKey(Vec<u8>);
+
+    impl DerivedKey {
+        pub(crate) fn new(passphrase: &str, salt: &[u8], len: usize) -> Self {
+            let mut key = vec![0u8; len];
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, ROUNDS, &mut key)
+                .expect("HMAC accepts keys of any length");
+            DerivedKey(key)
+        }
+
+        /// A munger borrowing this key, starting at offset zero.
+        pub fn munger(&self) -> super::Xorcism<'_> {
+            super::Xorcism::new(&self.0)
+        }
+    }
+
+    impl AsRef<[u8]> for DerivedKey {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+}
+
+#[cfg(feature = "encoding")]
+pub mod encoding {
+    //! Text encodings for munged bytes. XORed data is binary, transport
+    //! is usually text, and everyone was writing this glue themselves.
+
+    use base64::Engine;
+
+    /// Why a text-encoded ciphertext couldn't be decoded.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum DecodeError {
+        /// The input length is impossible for the encoding (hex needs an
+        /// even number of digits, base64 whole quads).
+        InvalidLength,
+        /// A character outside the encoding's alphabet.
+        InvalidCharacter(char),
+    }
+
+    pub(crate) fn to_hex(bytes: impl Iterator<Item = u8>) -> String {
+        bytes.map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub(crate) fn from_hex(hex: &str) -> Result<Vec<u8>, DecodeError> {
+        if !hex.len().is_multiple_of(2) {
+            return Err(DecodeError::InvalidLength);
+        }
+        hex.as_bytes()
+            .chunks_exact(2)
+            .map(|pair| {
+                let pair = std::str::from_utf8(pair)
+                    .map_err(|_| DecodeError::InvalidCharacter(char::REPLACEMENT_CHARACTER))?;
+                u8::from_str_radix(pair, 16).map_err(|_| {
+                    DecodeError::InvalidCharacter(
+                        pair.chars()
+                            .find(|c| !c.is_ascii_hexdigit())
+                            .expect("a non-hex character caused the parse failure"),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub(crate) fn to_base64(bytes: Vec<u8>) -> String {
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    }
+
+    pub(crate) fn from_base64(encoded: &str) -> Result<Vec<u8>, DecodeError> {
+        base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|err| match err {
+                base64::DecodeError::InvalidByte(index, _) => {
+                    DecodeError::InvalidCharacter(encoded[index..].chars().next().unwrap_or('?'))
+                }
+                _ => DecodeError::InvalidLength,
+            })
+    }
+}
+
+pub mod analysis {
+    //! Cryptanalysis of repeating-key XOR — the natural companion to the
+    //! munger, and a reminder of why it isn't encryption.
+
+    /// A recovered key, together with how English its decryption looks
+    /// (higher is better).
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct CandidateKey {
+        pub key: Vec<u8>,
+        pub score: f64,
+    }
+
+    fn hamming(a: &[u8], b: &[u8]) -> u32 {
+        a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+    }
+
+    /// Rank likely key lengths, best first, by the mean normalized
+    /// Hamming distance between consecutive ciphertext blocks: blocks
+    /// XORed with the same key bytes differ only as much as English does.
+    pub fn estimate_key_lengths(ciphertext: &[u8], max_len: usize) -> Vec<(usize, f64)> {
+        let mut scored = (1..=max_len.min(ciphertext.len() / 2))
+            .map(|len| {
+                let blocks = ciphertext.chunks_exact(len).take(8).collect::<Vec<_>>();
+                let pairs = blocks.windows(2).collect::<Vec<_>>();
+                let distance = pairs
+                    .iter()
+                    .map(|pair| f64::from(hamming(pair[0], pair[1])))
+                    .sum::<f64>()
+                    / (pairs.len() as f64 * len as f64);
+                (len, distance)
+            })
+            .collect::<Vec<_>>();
+        scored.sort_by(|a, b| a.1.total_cmp(&b.1));
+        scored
+    }
+
+    /// How plausible `bytes` are as English plaintext.
+    fn score_plaintext(bytes: impl IntoIterator<Item = u8>) -> f64 {
+        let (mut score, mut count) = (0.0, 0usize);
+        for byte in bytes {
+            count += 1;
+            score += if byte == b' ' {
+                13.0
+            } else if b"etaoin".contains(&byte) {
+                9.0
+            } else if b"shrdlu".contains(&byte) {
+                6.0
+            } else if byte.is_ascii_lowercase() {
+                2.0
+            } else if byte.is_ascii_uppercase() || b".,'".contains(&byte) {
+                1.0
+            } else if byte == b'\n' || byte.is_ascii_digit() || b"!?-".contains(&byte) {
+                0.5
+            } else if (0x20..=0x7e).contains(&byte) {
+                0.0
+            } else {
+                -20.0
+            };
+        }
+        if count == 0 {
+            0.0
+        } else {
+            score / count as f64
+        }
+    }
+
+    fn best_column_key(column: &[u8]) -> u8 {
+        (0..=u8::MAX)
+            .max_by(|&a, &b| {
+                let score_a = score_plaintext(column.iter().map(|&c| c ^ a));
+                let score_b = score_plaintext(column.iter().map(|&c| c ^ b));
+                score_a.total_cmp(&score_b)
+            })
+            .unwrap()
+    }
+
+    /// Recover the most likely key of exactly `key_len` bytes by solving
+    /// each key position as an independent single-byte XOR.
+    pub fn recover_key(ciphertext: &[u8], key_len: usize) -> Vec<u8> {
+        (0..key_len)
+            .map(|position| {
+                let column = ciphertext
+                    .iter()
+                    .skip(position)
+                    .step_by(key_len)
+                    .copied()
+                    .collect::<Vec<_>>();
+                best_column_key(&column)
+            })
+            .collect()
+    }
+
+    /// The whole pipeline: estimate key lengths, recover a key for each
+    /// of the most promising ones, and rank the results by how English
+    /// the decryption looks.
+    pub fn crack(ciphertext: &[u8], max_key_len: usize) -> Vec<CandidateKey> {
+        let mut candidates = estimate_key_lengths(ciphertext, max_key_len)
+            .into_iter()
+            .take(5)
+            .map(|(len, _)| {
+                let key = recover_key(ciphertext, len);
+                let score = score_plaintext(
+                    ciphertext
+                        .iter()
+                        .zip(key.iter().cycle())
+                        .map(|(&c, &k)| c ^ k),
+                );
+                CandidateKey { key, score }
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+}
+
+/// The iterator returned by [`Xorcism::munge`]. It forwards the input's
+/// size hints (and `ExactSizeIterator`, when the input provides it), so
+/// `collect::<Vec<u8>>()` allocates once instead of growing repeatedly.
+pub struct Munge<'a, 's, I> {
+    key: &'a [u8],
+    offset: &'s mut u64,
+    inner: I,
+}
+
+impl<'a, 's, I, B> Iterator for Munge<'a, 's, I>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<u8>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        // an empty key means "munging is the identity", and the identity
+        // of no key bytes is to produce nothing
+        if self.key.is_empty() {
+            return None;
+        }
+        let byte = self.inner.next()?;
+        let k = Xorcism::key_byte(self.key, *self.offset);
+        *self.offset += 1;
+        Some(byte.borrow() ^ k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.key.is_empty() {
+            (0, Some(0))
+        } else {
+            self.inner.size_hint()
+        }
+    }
+}
+
+impl<'a, 's, I, B> ExactSizeIterator for Munge<'a, 's, I>
+where
+    I: ExactSizeIterator<Item = B>,
+    B: Borrow<u8>,
+{
+}
 
 impl<'a> Xorcism<'a> {
     /// Create a new Xorcism munger from a key
@@ -82,7 +546,50 @@ impl<'a> Xorcism<'a> {
         K: AsRef<[u8]> + ?Sized + 'a,
     {
         Self {
-            key: key.as_ref().iter().cycle(),
+            key: key.as_ref(),
+            offset: 0,
+        }
+    }
+
+    fn key_byte(key: &[u8], offset: u64) -> u8 {
+        key[(offset % key.len() as u64) as usize]
+    }
+
+    /// Resume munging at byte `offset` of a stream, without replaying
+    /// the first `offset` bytes.
+    pub fn with_position<K>(key: &'a K, offset: u64) -> Self
+    where
+        K: AsRef<[u8]> + ?Sized + 'a,
+    {
+        let mut xorcism = Self::new(key);
+        xorcism.offset = offset;
+        xorcism
+    }
+
+    /// The number of bytes munged so far — save it alongside the stream
+    /// position to checkpoint a job, and hand it back to
+    /// [`Xorcism::with_position`] to resume.
+    pub fn position(&self) -> u64 {
+        self.offset
+    }
+
+    /// Jump the key cycle to an absolute stream offset.
+    #[cfg(feature = "io")]
+    fn set_position(&mut self, offset: u64) {
+        self.offset = offset;
+    }
+
+    /// Swap in a new key mid-stream, for protocols that rotate keys per
+    /// frame or per session. `mode` decides where the new key's cycle
+    /// starts.
+    pub fn rekey<K>(&mut self, new_key: &'a K, mode: Rekey)
+    where
+        K: AsRef<[u8]> + ?Sized + 'a,
+    {
+        self.key = new_key.as_ref();
+        match mode {
+            Rekey::Reset => self.offset = 0,
+            Rekey::Preserve => {}
         }
     }
 
@@ -91,11 +598,35 @@ impl<'a> Xorcism<'a> {
     /// Note that this is stateful: repeated calls are likely to produce different results,
     /// even with identical inputs.
     pub fn munge_in_place(&mut self, data: &mut [u8]) {
-        data.iter_mut()
-            .zip(&mut self.key)
-            .for_each(move |(byte, &k)| {
+        if self.key.is_empty() {
+            return;
+        }
+        let key = self.key;
+        let mut data = data;
+
+        // finish the current key cycle byte-at-a-time, so the bulk of
+        // the buffer can be XORed in whole key-length blocks
+        let misalignment = (self.offset % key.len() as u64) as usize;
+        if misalignment != 0 {
+            let head = (key.len() - misalignment).min(data.len());
+            let (aligned, rest) = { data }.split_at_mut(head);
+            for (byte, &k) in aligned.iter_mut().zip(&key[misalignment..]) {
                 *byte ^= k;
-            })
+            }
+            self.offset += head as u64;
+            data = rest;
+        }
+
+        self.offset += data.len() as u64;
+        let mut blocks = data.chunks_exact_mut(key.len());
+        for block in &mut blocks {
+            for (byte, &k) in block.iter_mut().zip(key) {
+                *byte ^= k;
+            }
+        }
+        for (byte, &k) in blocks.into_remainder().iter_mut().zip(key) {
+            *byte ^= k;
+        }
     }
 
     /// XOR each byte of the data with a byte from the key.
@@ -105,15 +636,91 @@ impl<'a> Xorcism<'a> {
     ///
     /// Should accept anything which has a cheap conversion to a byte iterator.
     /// Shouldn't matter whether the byte iterator's values are owned or borrowed.
-    pub fn munge<'s, D, I>(&'s mut self, data: D) -> impl Iterator<Item = u8> + Captures<'a> + 's
+    pub fn munge<'s, D, I>(&'s mut self, data: D) -> Munge<'a, 's, D::IntoIter>
     where
         D: IntoIterator<Item = I>,
-        D::IntoIter: 's,
         I: Borrow<u8>,
     {
-        data.into_iter()
-            .zip(&mut self.key)
-            .map(move |(byte, k)| byte.borrow() ^ k)
+        Munge {
+            key: self.key,
+            offset: &mut self.offset,
+            inner: data.into_iter(),
+        }
+    }
+
+    /// Munge `data` and return the result as lowercase hex.
+    #[cfg(feature = "encoding")]
+    pub fn munge_to_hex(&mut self, data: impl AsRef<[u8]>) -> String {
+        encoding::to_hex(self.munge(data.as_ref()))
+    }
+
+    /// Decode `hex` and munge the resulting bytes.
+    #[cfg(feature = "encoding")]
+    pub fn munge_from_hex(&mut self, hex: &str) -> Result<Vec<u8>, encoding::DecodeError> {
+        let bytes = encoding::from_hex(hex)?;
+        Ok(self.munge(bytes).collect())
+    }
+
+    /// Munge `data` and return the result as standard base64.
+    #[cfg(feature = "encoding")]
+    pub fn munge_to_base64(&mut self, data: impl AsRef<[u8]>) -> String {
+        encoding::to_base64(self.munge(data.as_ref()).collect())
+    }
+
+    /// Decode `encoded` as standard base64 and munge the resulting bytes.
+    #[cfg(feature = "encoding")]
+    pub fn munge_from_base64(&mut self, encoded: &str) -> Result<Vec<u8>, encoding::DecodeError> {
+        let bytes = encoding::from_base64(encoded)?;
+        Ok(self.munge(bytes).collect())
+    }
+
+    /// Derive a `len`-byte XOR key from a passphrase and salt with
+    /// PBKDF2-HMAC-SHA256. Borrow mungers from the returned key:
+    ///
+    /// ```
+    /// # #[cfg(feature = "kdf")] {
+    /// use xorcism::Xorcism;
+    /// let key = Xorcism::from_passphrase("correct horse", b"salt", 64);
+    /// let mut munger = key.munger();
+    /// # let _ = munger.munge(b"x").collect::<Vec<_>>();
+    /// # }
+    /// ```
+    #[cfg(feature = "kdf")]
+    pub fn from_passphrase(passphrase: &str, salt: &[u8], len: usize) -> kdf::DerivedKey {
+        kdf::DerivedKey::new(passphrase, salt, len)
+    }
+
+    /// Pipe `reader` to `writer` through the munger with an internal
+    /// buffer, returning the number of bytes copied.
+    #[cfg(feature = "io")]
+    pub fn copy(&mut self, reader: impl Read, writer: impl Write) -> std::io::Result<u64> {
+        self.copy_with_progress(reader, writer, |_| {})
+    }
+
+    /// [`Xorcism::copy`], invoking `progress` with the running byte
+    /// total after every buffer written.
+    #[cfg(feature = "io")]
+    pub fn copy_with_progress(
+        &mut self,
+        mut reader: impl Read,
+        mut writer: impl Write,
+        mut progress: impl FnMut(u64),
+    ) -> std::io::Result<u64> {
+        let mut buf = [0u8; 8192];
+        let mut total = 0u64;
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.munge_in_place(&mut buf[..n]);
+            writer.write_all(&buf[..n])?;
+            total += n as u64;
+            progress(total);
+        }
+        Ok(total)
     }
 
     #[cfg(feature = "io")]
@@ -125,4 +732,20 @@ impl<'a> Xorcism<'a> {
     pub fn writer(self, writer: impl Write + 'a) -> impl Write + 'a {
         XorcismWriter::new(self, writer)
     }
+
+    #[cfg(feature = "async-io")]
+    pub fn async_reader(
+        self,
+        reader: impl futures_io::AsyncRead + Unpin + 'a,
+    ) -> impl futures_io::AsyncRead + Unpin + 'a {
+        xorcism_async::AsyncXorcismReader::new(self, reader)
+    }
+
+    #[cfg(feature = "async-io")]
+    pub fn async_writer(
+        self,
+        writer: impl futures_io::AsyncWrite + Unpin + 'a,
+    ) -> impl futures_io::AsyncWrite + Unpin + 'a {
+        xorcism_async::AsyncXorcismWriter::new(self, writer)
+    }
 }
@@ -0,0 +1,42 @@
+use xorcism::analysis::{crack, estimate_key_lengths, recover_key};
+use xorcism::Xorcism;
+
+const PLAINTEXT: &[u8] = b"It was the best of times, it was the worst of times, it was \
+the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was \
+the epoch of incredulity, it was the season of light, it was the season of darkness, \
+it was the spring of hope, it was the winter of despair.";
+
+fn encrypt(key: &str) -> Vec<u8> {
+    Xorcism::new(key).munge(PLAINTEXT).collect()
+}
+
+#[test]
+fn the_true_key_length_ranks_highly() {
+    let ciphertext = encrypt("opaque");
+    let ranked = estimate_key_lengths(&ciphertext, 12);
+    let top: Vec<usize> = ranked.iter().take(3).map(|&(len, _)| len).collect();
+    assert!(top.contains(&6), "6 not in top lengths {:?}", top);
+}
+
+#[test]
+fn recover_key_finds_the_key_given_its_length() {
+    let ciphertext = encrypt("secret");
+    assert_eq!(recover_key(&ciphertext, 6), b"secret");
+}
+
+#[test]
+fn crack_decrypts_without_knowing_anything() {
+    let ciphertext = encrypt("xyzzy");
+    let best = &crack(&ciphertext, 10)[0];
+    let decrypted: Vec<u8> = Xorcism::new(&best.key).munge(&ciphertext).collect();
+    assert_eq!(decrypted, PLAINTEXT);
+}
+
+#[test]
+fn candidates_are_ranked_best_first() {
+    let ciphertext = encrypt("key");
+    let candidates = crack(&ciphertext, 8);
+    assert!(candidates
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+}
@@ -0,0 +1,49 @@
+use xorcism::Xorcism;
+
+const KEY: &str = "rotor";
+
+#[test]
+fn position_counts_munged_bytes() {
+    let mut munger = Xorcism::new(KEY);
+    assert_eq!(munger.position(), 0);
+    munger.munge_in_place(&mut [0u8; 13]);
+    assert_eq!(munger.position(), 13);
+    munger.munge(b"abc").for_each(drop);
+    assert_eq!(munger.position(), 16);
+}
+
+#[test]
+fn resuming_matches_an_uninterrupted_run() {
+    let payload = b"a reasonably long payload split at an awkward offset";
+    let full = Xorcism::new(KEY).munge(payload).collect::<Vec<u8>>();
+
+    let mut first = Xorcism::new(KEY);
+    let head = first.munge(&payload[..17]).collect::<Vec<u8>>();
+    let checkpoint = first.position();
+
+    let mut resumed = Xorcism::with_position(KEY, checkpoint);
+    let tail = resumed.munge(&payload[17..]).collect::<Vec<u8>>();
+
+    assert_eq!([head, tail].concat(), full);
+}
+
+#[test]
+fn with_position_wraps_around_the_key() {
+    let mut a = Xorcism::with_position(KEY, 5);
+    let mut b = Xorcism::with_position(KEY, 0);
+    let x = a.munge(b"stream").collect::<Vec<u8>>();
+    let y = b.munge(b"stream").collect::<Vec<u8>>();
+    // a five-byte key means offsets 5 and 0 munge identically
+    assert_eq!(x, y);
+}
+
+#[test]
+fn lazy_munge_advances_position_only_when_consumed() {
+    let mut munger = Xorcism::new(KEY);
+    {
+        let mut iter = munger.munge(b"lazy");
+        iter.next();
+        iter.next();
+    }
+    assert_eq!(munger.position(), 2);
+}
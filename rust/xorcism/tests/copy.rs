@@ -0,0 +1,53 @@
+#![cfg(feature = "io")]
+
+use std::io::Cursor;
+use xorcism::Xorcism;
+
+#[test]
+fn copy_munges_the_whole_stream() {
+    let payload = b"pipe me through the munger".to_vec();
+    let mut out = Vec::new();
+    let copied = Xorcism::new("key")
+        .copy(Cursor::new(&payload), &mut out)
+        .unwrap();
+    assert_eq!(copied, payload.len() as u64);
+    assert_eq!(out, Xorcism::new("key").munge(&payload).collect::<Vec<_>>());
+}
+
+#[test]
+fn copy_round_trips() {
+    let payload = vec![42u8; 100_000];
+    let mut encrypted = Vec::new();
+    Xorcism::new("abcde")
+        .copy(Cursor::new(&payload), &mut encrypted)
+        .unwrap();
+    let mut decrypted = Vec::new();
+    Xorcism::new("abcde")
+        .copy(Cursor::new(&encrypted), &mut decrypted)
+        .unwrap();
+    assert_eq!(decrypted, payload);
+}
+
+#[test]
+fn progress_reports_running_totals() {
+    let payload = vec![0u8; 20_000];
+    let mut totals = Vec::new();
+    let mut out = Vec::new();
+    Xorcism::new("k")
+        .copy_with_progress(Cursor::new(&payload), &mut out, |total| totals.push(total))
+        .unwrap();
+    assert_eq!(totals.last(), Some(&20_000));
+    assert!(totals.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn empty_streams_copy_zero_bytes() {
+    let mut out = Vec::new();
+    assert_eq!(
+        Xorcism::new("key")
+            .copy(Cursor::new(&[]), &mut out)
+            .unwrap(),
+        0
+    );
+    assert!(out.is_empty());
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "encoding")]
+
+use xorcism::encoding::DecodeError;
+use xorcism::Xorcism;
+
+#[test]
+fn hex_round_trips() {
+    let hex = Xorcism::new("frame").munge_to_hex("rotate the key");
+    let decoded = Xorcism::new("frame").munge_from_hex(&hex).unwrap();
+    assert_eq!(decoded, b"rotate the key");
+}
+
+#[test]
+fn hex_is_lowercase_pairs() {
+    let hex = Xorcism::new(&[0u8]).munge_to_hex([0x0f, 0xa0]);
+    assert_eq!(hex, "0fa0");
+}
+
+#[test]
+fn odd_length_hex_is_rejected() {
+    assert_eq!(
+        Xorcism::new("key").munge_from_hex("abc"),
+        Err(DecodeError::InvalidLength)
+    );
+}
+
+#[test]
+fn non_hex_digit_is_reported() {
+    assert_eq!(
+        Xorcism::new("key").munge_from_hex("0g"),
+        Err(DecodeError::InvalidCharacter('g'))
+    );
+}
+
+#[test]
+fn base64_round_trips() {
+    let encoded = Xorcism::new("frame").munge_to_base64("rotate the key");
+    let decoded = Xorcism::new("frame").munge_from_base64(&encoded).unwrap();
+    assert_eq!(decoded, b"rotate the key");
+}
+
+#[test]
+fn base64_rejects_foreign_characters() {
+    assert_eq!(
+        Xorcism::new("key").munge_from_base64("ab#d"),
+        Err(DecodeError::InvalidCharacter('#'))
+    );
+}
+
+#[test]
+fn encoding_advances_the_key_like_plain_munging() {
+    let mut text = Xorcism::new("abcde");
+    let mut plain = Xorcism::new("abcde");
+    let hex = text.munge_to_hex("first frame");
+    plain.munge(b"first frame").for_each(drop);
+    assert_eq!(
+        text.munge_to_hex("second frame"),
+        plain.munge_to_hex("second frame")
+    );
+    drop(hex);
+}
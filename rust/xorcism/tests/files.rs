@@ -0,0 +1,64 @@
+#![cfg(feature = "io")]
+
+use std::fs;
+use std::path::PathBuf;
+use xorcism::files::{encrypt_file, encrypt_file_in_place, encrypt_file_with_progress};
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("xorcism-files-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn encrypt_then_decrypt_round_trips() {
+    let (plain, cipher, back) = (
+        scratch("plain.txt"),
+        scratch("cipher.bin"),
+        scratch("back.txt"),
+    );
+    fs::write(&plain, b"the quick brown fox").unwrap();
+    let written = encrypt_file(&plain, &cipher, "vexing").unwrap();
+    assert_eq!(written, 19);
+    assert_ne!(fs::read(&cipher).unwrap(), b"the quick brown fox");
+    encrypt_file(&cipher, &back, "vexing").unwrap();
+    assert_eq!(fs::read(&back).unwrap(), b"the quick brown fox");
+}
+
+#[test]
+fn in_place_round_trips() {
+    let path = scratch("in-place.bin");
+    fs::write(&path, b"overwrite me carefully").unwrap();
+    encrypt_file_in_place(&path, "key").unwrap();
+    assert_ne!(fs::read(&path).unwrap(), b"overwrite me carefully");
+    encrypt_file_in_place(&path, "key").unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"overwrite me carefully");
+}
+
+#[test]
+fn progress_reports_a_running_total() {
+    let (src, dst) = (scratch("progress-src.bin"), scratch("progress-dst.bin"));
+    fs::write(&src, vec![0u8; 20_000]).unwrap();
+    let mut reports = Vec::new();
+    encrypt_file_with_progress(&src, &dst, "key", |total| reports.push(total)).unwrap();
+    assert!(reports.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(reports.last(), Some(&20_000));
+}
+
+#[test]
+fn a_missing_source_leaves_no_temp_file_behind() {
+    let dst = scratch("never-written.bin");
+    let missing = scratch("does-not-exist.bin");
+    assert!(encrypt_file(&missing, &dst, "key").is_err());
+    assert!(!dst.exists());
+    assert!(!dst.with_file_name("never-written.bin.tmp").exists());
+}
+
+#[test]
+fn the_destination_is_replaced_wholesale() {
+    let (src, dst) = (scratch("replace-src.bin"), scratch("replace-dst.bin"));
+    fs::write(&src, b"short").unwrap();
+    fs::write(&dst, b"a much longer pre-existing destination file").unwrap();
+    encrypt_file(&src, &dst, "key").unwrap();
+    assert_eq!(fs::read(&dst).unwrap().len(), 5);
+}
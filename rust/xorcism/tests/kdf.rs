@@ -0,0 +1,34 @@
+#![cfg(feature = "kdf")]
+
+use xorcism::Xorcism;
+
+#[test]
+fn derivation_is_deterministic() {
+    let a = Xorcism::from_passphrase("hunter2", b"pepper", 32);
+    let b = Xorcism::from_passphrase("hunter2", b"pepper", 32);
+    assert_eq!(a.as_ref(), b.as_ref());
+    assert_eq!(a.as_ref().len(), 32);
+}
+
+#[test]
+fn passphrase_and_salt_both_matter() {
+    let base = Xorcism::from_passphrase("hunter2", b"pepper", 32);
+    let other_pass = Xorcism::from_passphrase("hunter3", b"pepper", 32);
+    let other_salt = Xorcism::from_passphrase("hunter2", b"paprika", 32);
+    assert_ne!(base.as_ref(), other_pass.as_ref());
+    assert_ne!(base.as_ref(), other_salt.as_ref());
+}
+
+#[test]
+fn the_key_is_not_the_passphrase() {
+    let key = Xorcism::from_passphrase("hunter2", b"salt", 7);
+    assert_ne!(key.as_ref(), b"hunter2");
+}
+
+#[test]
+fn derived_keys_munge_round_trip() {
+    let key = Xorcism::from_passphrase("open sesame", b"cave", 64);
+    let ciphertext = key.munger().munge(b"forty thieves").collect::<Vec<_>>();
+    let plaintext = key.munger().munge(&ciphertext).collect::<Vec<_>>();
+    assert_eq!(plaintext, b"forty thieves");
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "io")]
+
+use std::io::{Cursor, Read, Write};
+use xorcism::xorcism_io::{XorcismReader, XorcismWriter};
+use xorcism::{Munger, Xorcism};
+
+/// A toy rolling cipher: each byte is XORed with a counter.
+#[derive(Clone, Default)]
+struct Rolling {
+    counter: u8,
+}
+
+impl Munger for Rolling {
+    fn transform(&mut self, data: &mut [u8]) {
+        for byte in data {
+            *byte ^= self.counter;
+            self.counter = self.counter.wrapping_add(1);
+        }
+    }
+}
+
+#[test]
+fn xorcism_transforms_like_munge() {
+    let mut munger = Xorcism::new("key");
+    let mut data = *b"hello world";
+    munger.transform(&mut data);
+    let expected: Vec<u8> = Xorcism::new("key").munge(b"hello world").collect();
+    assert_eq!(data.to_vec(), expected);
+}
+
+#[test]
+fn reader_accepts_any_munger() {
+    let mut obfuscated = *b"attack at dawn";
+    Rolling::default().transform(&mut obfuscated);
+    let mut reader = XorcismReader::new(Rolling::default(), Cursor::new(obfuscated));
+    let mut decoded = Vec::new();
+    reader.read_to_end(&mut decoded).unwrap();
+    assert_eq!(decoded, b"attack at dawn");
+}
+
+#[test]
+fn writer_accepts_any_munger() {
+    let mut out = Vec::new();
+    {
+        let mut writer = XorcismWriter::new(Rolling::default(), &mut out);
+        writer.write_all(b"attack at dawn").unwrap();
+    }
+    let mut expected = *b"attack at dawn";
+    Rolling::default().transform(&mut expected);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn rolling_state_survives_split_writes() {
+    let mut whole = Vec::new();
+    {
+        let mut writer = XorcismWriter::new(Rolling::default(), &mut whole);
+        writer.write_all(b"attack at dawn").unwrap();
+    }
+
+    let mut split = Vec::new();
+    {
+        let mut writer = XorcismWriter::new(Rolling::default(), &mut split);
+        writer.write_all(b"attack ").unwrap();
+        writer.write_all(b"at dawn").unwrap();
+    }
+
+    assert_eq!(whole, split);
+}
@@ -0,0 +1,45 @@
+use xorcism::{Rekey, Xorcism};
+
+#[test]
+fn reset_starts_the_new_key_from_its_first_byte() {
+    let mut munger = Xorcism::new("old");
+    munger.munge(b"frame one").for_each(drop);
+    munger.rekey("new", Rekey::Reset);
+    let munged: Vec<u8> = munger.munge(b"frame two").collect();
+    let fresh: Vec<u8> = Xorcism::new("new").munge(b"frame two").collect();
+    assert_eq!(munged, fresh);
+}
+
+#[test]
+fn preserve_keeps_the_absolute_offset() {
+    let mut munger = Xorcism::new("old");
+    munger.munge(b"frame").for_each(drop);
+    munger.rekey("newer", Rekey::Preserve);
+    let munged: Vec<u8> = munger.munge(b"two").collect();
+    let resumed: Vec<u8> = Xorcism::with_position("newer", 5).munge(b"two").collect();
+    assert_eq!(munged, resumed);
+}
+
+#[test]
+fn rekeying_decrypts_a_rotated_stream() {
+    let (first_key, second_key) = ("alpha", "bravo");
+    let mut sender = Xorcism::new(first_key);
+    let mut frame_one: Vec<u8> = sender.munge(b"first frame").collect();
+    sender.rekey(second_key, Rekey::Reset);
+    let frame_two: Vec<u8> = sender.munge(b"second frame").collect();
+
+    let mut receiver = Xorcism::new(first_key);
+    receiver.munge_in_place(&mut frame_one);
+    receiver.rekey(second_key, Rekey::Reset);
+    let decrypted: Vec<u8> = receiver.munge(&frame_two).collect();
+    assert_eq!(frame_one, b"first frame");
+    assert_eq!(decrypted, b"second frame");
+}
+
+#[test]
+fn reset_is_reflected_in_position() {
+    let mut munger = Xorcism::new("key");
+    munger.munge(b"some bytes").for_each(drop);
+    munger.rekey("other", Rekey::Reset);
+    assert_eq!(munger.position(), 0);
+}
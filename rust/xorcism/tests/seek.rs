@@ -0,0 +1,48 @@
+#![cfg(feature = "io")]
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use xorcism::xorcism_io::XorcismReader;
+use xorcism::Xorcism;
+
+const KEY: &str = "opaque";
+const PLAINTEXT: &[u8] = b"the magic words are squeamish ossifrage";
+
+fn ciphertext() -> Vec<u8> {
+    Xorcism::new(KEY).munge(PLAINTEXT).collect()
+}
+
+#[test]
+fn seeking_to_the_middle_decodes_correctly() {
+    let mut reader = XorcismReader::new(Xorcism::new(KEY), Cursor::new(ciphertext()));
+    reader.seek(SeekFrom::Start(10)).unwrap();
+    let mut tail = Vec::new();
+    reader.read_to_end(&mut tail).unwrap();
+    assert_eq!(tail, &PLAINTEXT[10..]);
+}
+
+#[test]
+fn relative_and_end_seeks_work() {
+    let mut reader = XorcismReader::new(Xorcism::new(KEY), Cursor::new(ciphertext()));
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf).unwrap();
+    reader.seek(SeekFrom::Current(13)).unwrap();
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, &PLAINTEXT[17..21]);
+
+    reader.seek(SeekFrom::End(-5)).unwrap();
+    let mut tail = Vec::new();
+    reader.read_to_end(&mut tail).unwrap();
+    assert_eq!(tail, &PLAINTEXT[PLAINTEXT.len() - 5..]);
+}
+
+#[test]
+fn rewinding_replays_the_stream() {
+    let mut reader = XorcismReader::new(Xorcism::new(KEY), Cursor::new(ciphertext()));
+    let mut first = Vec::new();
+    reader.read_to_end(&mut first).unwrap();
+    reader.seek(SeekFrom::Start(0)).unwrap();
+    let mut second = Vec::new();
+    reader.read_to_end(&mut second).unwrap();
+    assert_eq!(first, PLAINTEXT);
+    assert_eq!(first, second);
+}
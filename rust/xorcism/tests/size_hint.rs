@@ -0,0 +1,35 @@
+use xorcism::Xorcism;
+
+#[test]
+fn munge_reports_the_input_length() {
+    let mut munger = Xorcism::new("key");
+    let iter = munger.munge(b"a dozen bytes");
+    assert_eq!(iter.size_hint(), (13, Some(13)));
+    assert_eq!(iter.len(), 13);
+}
+
+#[test]
+fn len_shrinks_as_bytes_are_consumed() {
+    let mut munger = Xorcism::new("key");
+    let mut iter = munger.munge(b"abcdef");
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 4);
+}
+
+#[test]
+fn an_empty_key_reports_zero() {
+    let mut munger = Xorcism::new("");
+    let iter = munger.munge(b"nothing comes out");
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn unsized_inputs_still_munge() {
+    let mut munger = Xorcism::new("key");
+    let data = std::iter::repeat_n(0xa5u8, 4).filter(|_| true);
+    let munged: Vec<u8> = munger.munge(data).collect();
+    let expected: Vec<u8> = Xorcism::new("key").munge(&[0xa5u8; 4]).collect();
+    assert_eq!(munged, expected);
+}